@@ -0,0 +1,216 @@
+/*
+ * Benchmarks every day: its parsing step when it has a separate one, part 1 and part 2, on the
+ * real input `src/inputs/NN.txt` when there is one, then on generated inputs of increasing size
+ * (see `advent_of_code::generators`) to show how each part scales.
+ *
+ * example: `cargo bench`, or `cargo bench -- 8 12` for some days only.
+ * `BENCH_TIME` sets the time spent measuring each benchmark in milliseconds (default 300).
+ */
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use advent_of_code::helpers::rng::Rng;
+use advent_of_code::registry::{self, Part};
+use advent_of_code::{
+    day07, day08, day09, day10, day12, day14, day15, generators, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+type Step = fn(&str);
+
+/// A step measured with its name.
+type Measured = (&'static str, Box<dyn Fn(&str)>);
+
+/// What to measure besides the parts of the day, found in `advent_of_code::registry`.
+struct Day {
+    day: u8,
+    parse: Option<Step>,
+    /// Sizes of the generated inputs, small enough for the slowest part to stay measurable.
+    sizes: &'static [usize],
+}
+
+macro_rules! day {
+    ($day:expr, $sizes:expr) => {
+        day!($day, None, $sizes)
+    };
+    ($day:expr, $parse:expr, $sizes:expr) => {
+        Day {
+            day: $day,
+            parse: $parse,
+            sizes: $sizes,
+        }
+    };
+}
+
+fn days() -> Vec<Day> {
+    vec![
+        day!(1, &[100, 1_000, 10_000]),
+        day!(2, &[100, 1_000, 10_000]),
+        day!(3, &[100, 1_000, 10_000]),
+        day!(4, &[100, 1_000, 10_000]),
+        day!(5, &[100, 1_000, 10_000]),
+        day!(6, &[1_000, 10_000, 100_000]),
+        day!(
+            7,
+            Some(|input| {
+                black_box(day07::read_filesystem(black_box(input)));
+            }),
+            &[10, 100, 1_000]
+        ),
+        day!(
+            8,
+            Some(|input| {
+                black_box(day08::init_height_matrix(black_box(input)));
+            }),
+            &[10, 30, 100, 300]
+        ),
+        day!(
+            9,
+            Some(|input| {
+                black_box(day09::parse_moves(black_box(input)));
+            }),
+            &[100, 1_000, 10_000]
+        ),
+        day!(
+            10,
+            Some(|input| {
+                black_box(input.lines().map(day10::parse_command).count());
+            }),
+            &[100, 1_000, 10_000]
+        ),
+        day!(11, &[10, 100, 1_000]),
+        day!(
+            12,
+            Some(|input| {
+                black_box(day12::parse_heightmap(black_box(input)));
+            }),
+            &[10, 30, 100, 300]
+        ),
+        day!(13, &[100, 1_000, 10_000]),
+        day!(
+            14,
+            Some(|input| {
+                black_box(day14::parse_input(black_box(input)));
+            }),
+            &[10, 30, 100, 300]
+        ),
+        day!(
+            15,
+            Some(|input| {
+                black_box(day15::parse_sensors(black_box(input)));
+            }),
+            &[4, 16, 64]
+        ),
+    ]
+}
+
+/// Median time of one call to `step`, over samples filling about `budget`. Steps slower than
+/// the budget are only run once.
+fn measure(step: &dyn Fn(&str), input: &str, budget: Duration) -> Duration {
+    // warm up, and estimate how many calls fit in a sample
+    let timer = Instant::now();
+    step(input);
+    let once = timer.elapsed();
+    if once >= budget / 2 {
+        return once;
+    }
+
+    const SAMPLES: u32 = 10;
+    let per_sample = budget / SAMPLES;
+    let iterations = (per_sample.as_nanos() / once.as_nanos().max(1)).clamp(1, 1_000_000) as u32;
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let timer = Instant::now();
+            for _ in 0..iterations {
+                step(input);
+            }
+            timer.elapsed() / iterations
+        })
+        .collect();
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+fn print_row(name: &str, label: &str, time: Duration, growth: Option<f64>) {
+    let growth = match growth {
+        Some(exponent) => format!("  {}~n^{:.1}{}", ANSI_ITALIC, exponent, ANSI_RESET),
+        None => String::new(),
+    };
+    println!("{:<8} {:<16} {:>12.2?}{}", name, label, time, growth);
+}
+
+fn bench_part(part: Part) -> Box<dyn Fn(&str)> {
+    Box::new(move |input| {
+        black_box(part(black_box(input)));
+    })
+}
+
+fn bench_day(day: &Day, budget: Duration) {
+    println!("{}day {:02}{}", ANSI_BOLD, day.day, ANSI_RESET);
+    let Some(solution) = registry::solution(day.day) else {
+        println!("{}not solved, skipped{}", ANSI_ITALIC, ANSI_RESET);
+        return;
+    };
+    let parse = day
+        .parse
+        .map(|parse| ("parse", Box::new(parse) as Box<dyn Fn(&str)>));
+    let steps: Vec<Measured> = parse
+        .into_iter()
+        .chain([
+            ("part 1", bench_part(solution.part_one)),
+            ("part 2", bench_part(solution.part_two)),
+        ])
+        .collect();
+
+    match advent_of_code::try_read_file("inputs", day.day) {
+        Some(input) if !input.trim().is_empty() => {
+            for (name, step) in &steps {
+                print_row(name, "input", measure(step, &input, budget), None);
+            }
+        }
+        _ => println!("{}no input, skipped{}", ANSI_ITALIC, ANSI_RESET),
+    }
+
+    let Some(generate) = generators::generator(day.day) else {
+        return;
+    };
+    let inputs: Vec<String> = day
+        .sizes
+        .iter()
+        .map(|&size| generate(&mut Rng::new(2022), size))
+        .collect();
+    for (name, step) in &steps {
+        let mut previous: Option<(usize, Duration)> = None;
+        for (&size, input) in day.sizes.iter().zip(&inputs) {
+            let time = measure(step, input, budget);
+            // how the time grows with the size, from the previous size
+            let growth = previous.map(|(previous_size, previous_time)| {
+                (time.as_secs_f64() / previous_time.as_secs_f64()).ln()
+                    / (size as f64 / previous_size as f64).ln()
+            });
+            print_row(name, &format!("generated {}", size), time, growth);
+            previous = Some((size, time));
+        }
+    }
+}
+
+fn main() {
+    // cargo passes `--bench`, anything else selects days
+    let selected: Vec<u8> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    let budget = Duration::from_millis(
+        env::var("BENCH_TIME")
+            .ok()
+            .and_then(|time| time.parse().ok())
+            .unwrap_or(300),
+    );
+
+    for day in days() {
+        if selected.is_empty() || selected.contains(&day.day) {
+            bench_day(&day, budget);
+        }
+    }
+}
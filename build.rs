@@ -0,0 +1,74 @@
+/*
+ * With the `embed-inputs` feature, generates the tables of files that `read_file` and
+ * `inputs::all` embed with `include_str!`: the inputs, examples and recorded answers of every
+ * day, and every team member's input and answers (see `advent_of_code::inputs`). Only files
+ * present at build time are listed, so that missing days still compile and fall back to
+ * reading from disk.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn include(path: &Path) -> String {
+    format!("include_str!({:?})", path)
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let mut files = String::new();
+    let mut members = String::new();
+    for folder in ["inputs", "examples", "answers"] {
+        let dir = root.join("src").join(folder);
+        // also picks up files added or removed later
+        println!("cargo:rerun-if-changed={}", dir.display());
+        for day in 1..=25 {
+            let path = dir.join(format!("{:02}.txt", day));
+            if path.is_file() {
+                files.push_str(&format!("    ({:?}, {}, {}),\n", folder, day, include(&path)));
+            }
+        }
+    }
+
+    // `src/inputs/NN/<name>.txt`, with the answers in `<name>.answers` when there are some
+    for day in 1..=25 {
+        let dir = root.join("src").join("inputs").join(format!("{:02}", day));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .collect();
+        paths.sort();
+        for path in paths {
+            if path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let answers = path.with_extension("answers");
+            let answers = match answers.is_file() {
+                true => format!("Some({})", include(&answers)),
+                false => "None".to_string(),
+            };
+            members.push_str(&format!(
+                "    ({}, {:?}, {}, {}),\n",
+                day,
+                name,
+                include(&path),
+                answers
+            ));
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded.rs");
+    let table = format!(
+        "pub static FILES: &[(&str, u8, &str)] = &[\n{}];\n\n\
+         pub static MEMBERS: &[(u8, &str, &str, Option<&str>)] = &[\n{}];\n",
+        files, members
+    );
+    fs::write(out, table).unwrap();
+}
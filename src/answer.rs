@@ -0,0 +1,173 @@
+/*
+ * Common type for the answers of every part, whatever type the part itself returns.
+ * Used by `solve!` to print answers and by `registry` to run any day, and meant for storing,
+ * comparing and submitting them.
+ */
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::helpers::ocr::read_letters;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Any integer answer, wide enough for every integer type the parts return.
+    Integer(i128),
+    Text(String),
+    /// An answer drawn as a multi-line image, with the letters read from it when possible.
+    Rendered {
+        image: String,
+        text: Option<String>,
+    },
+}
+
+impl Answer {
+    /// Wraps a drawn answer, reading its letters with [`read_letters`].
+    pub fn rendered(image: impl Into<String>) -> Answer {
+        let image = image.into();
+        let text = read_letters(&image);
+        Answer::Rendered { image, text }
+    }
+
+    /// The multi-line image of a rendered answer.
+    pub fn image(&self) -> Option<&str> {
+        match self {
+            Answer::Rendered { image, .. } => Some(image),
+            _ => None,
+        }
+    }
+
+    /// The one-line form of the answer, as submitted. `None` for images that couldn't be read.
+    pub fn short(&self) -> Option<String> {
+        match self {
+            Answer::Integer(value) => Some(value.to_string()),
+            Answer::Text(text) => Some(text.clone()),
+            Answer::Rendered { text, .. } => text.clone(),
+        }
+    }
+}
+
+/// The short form, or the whole image when it couldn't be read.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Rendered {
+                text: Some(text), ..
+            } => f.write_str(text),
+            Answer::Rendered { image, text: None } => f.write_str(image),
+        }
+    }
+}
+
+/// Reads back a stored answer: an integer if it parses as one, text otherwise.
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Self::Err> {
+        let s = s.trim();
+        Ok(match s.parse() {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Answer {
+                Answer::Integer(value as i128)
+            }
+        })*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Where the answers for a day's input are recorded: `src/answers/NN.txt`.
+pub fn recorded_path(day: u8) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap();
+    cwd.join("src")
+        .join("answers")
+        .join(format!("{:02}.txt", day))
+}
+
+/// Recorded answers: the answer to part 1 on the first line and to part 2 on the second,
+/// left empty while unknown.
+pub fn parse_recorded(text: &str) -> [Option<Answer>; 2] {
+    let mut answers = text.lines().map(|line| match line.trim() {
+        "" => None,
+        answer => answer.parse().ok(),
+    });
+    [answers.next().flatten(), answers.next().flatten()]
+}
+
+/// The answers recorded in the file at `path`, none if there isn't one.
+pub fn read_recorded(path: &Path) -> [Option<Answer>; 2] {
+    parse_recorded(&fs::read_to_string(path).unwrap_or_default())
+}
+
+/// The answers recorded for the input of `day`, embedded in the binary with the
+/// `embed-inputs` feature like the inputs themselves.
+pub fn recorded(day: u8) -> [Option<Answer>; 2] {
+    parse_recorded(&crate::try_read_file("answers", day).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(24000_u32), Answer::Integer(24000));
+        assert_eq!(Answer::from(-3_isize), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from("CMZ".to_string()), Answer::Text("CMZ".into()));
+        assert_eq!("56000011".parse(), Ok(Answer::from(56000011_i64)));
+        assert_eq!(" MCD\n".parse(), Ok(Answer::from("MCD")));
+    }
+
+    #[test]
+    fn test_parse_recorded() {
+        assert_eq!(
+            parse_recorded("24000\nCMZ\n"),
+            [Some(Answer::from(24000)), Some(Answer::from("CMZ"))]
+        );
+        assert_eq!(parse_recorded("\n45000"), [None, Some(Answer::from(45000))]);
+        assert_eq!(parse_recorded(""), [None, None]);
+    }
+
+    #[test]
+    fn test_rendered() {
+        let letters = "\
+####.#..#
+#....#..#
+###..####
+#....#..#
+#....#..#
+####.#..#";
+        let answer = Answer::rendered(letters);
+        assert_eq!(answer.short(), Some("EH".to_string()));
+        assert_eq!(answer.image(), Some(letters));
+        assert_eq!(answer.to_string(), "EH");
+
+        let unreadable = Answer::rendered("##..##");
+        assert_eq!(unreadable.short(), None);
+        assert_eq!(unreadable.to_string(), "##..##");
+    }
+}
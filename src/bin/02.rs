@@ -1,128 +1,72 @@
-use std::collections::HashMap;
-use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 
+use advent_of_code::day02::{part_one, part_two, Column, Game};
 
-enum Round {
-    Win,
-    Lose,
-    Draw,
+/// `cargo solve 02 -- --game <file>` plays every input of the day with the game defined in
+/// the file (see `advent_of_code::day02::ROCK_PAPER_SCISSORS`) instead of the puzzle's, and
+/// `--analyze` describes how good the strategy guides are instead of solving.
+struct Args {
+    game: Option<Game>,
+    analyze: bool,
 }
 
-impl Display for Round {
-    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        let description = match *self {
-            Round::Win => "Won",
-            Round::Lose => "Lost",
-            _ => "Draw"
-        };
-        f.write_str(description)
-    }
-}
-
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut shapes_codes = HashMap::new();
-    shapes_codes.insert("A", "Rock");
-    shapes_codes.insert("X", "Rock");
-    shapes_codes.insert("B", "Paper");
-    shapes_codes.insert("Y", "Paper");
-    shapes_codes.insert("C", "Scissors");
-    shapes_codes.insert("Z", "Scissors");
-    let mut shapes_points = HashMap::new();
-    shapes_points.insert("Rock", 1);
-    shapes_points.insert("Paper", 2);
-    shapes_points.insert("Scissors", 3);
-
-    let mut shapes_counters = HashMap::new();
-    shapes_counters.insert("Rock", "Paper");
-    shapes_counters.insert("Paper", "Scissors");
-    shapes_counters.insert("Scissors", "Rock");
-
-    let mut total_score: u32 = 0;
-
-    for line in input.lines() {
-        let round = line.split(" ").collect::<Vec<&str>>();
-        let opponent = shapes_codes.get(round[0]).unwrap();
-        let me = shapes_codes.get(round[1]).unwrap();
-        let round_result = match shapes_counters.get(opponent).unwrap() {
-            &x if &x == me => Round::Win,
-            &x if (me == opponent) => Round::Draw,
-            _ => Round::Lose
-        };
-
-        match round_result {
-            Round::Win => total_score += (6 + shapes_points.get(me).unwrap()),
-            Round::Draw => total_score += (3 + shapes_points.get(me).unwrap()),
-            Round::Lose => total_score += (0 + shapes_points.get(me).unwrap()),
+fn parse_args() -> Args {
+    let mut args = pico_args::Arguments::from_env();
+    let analyze = args.contains("--analyze");
+    let path: Option<PathBuf> = args.opt_value_from_str("--game").ok().flatten();
+    let game = path.map(|path| match Game::load(&path) {
+        Ok(game) => game,
+        Err(e) => {
+            eprintln!("Invalid game {}: {}", path.display(), e);
+            std::process::exit(1);
         }
-        println!("Opponent played {} and you played {}. Result : {}",opponent,me,round_result );
-    }
-    Some(total_score)
+    });
+    Args { game, analyze }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut shapes_codes = HashMap::new();
-    shapes_codes.insert("A", "Rock");
-    shapes_codes.insert("X", "Rock");
-    shapes_codes.insert("B", "Paper");
-    shapes_codes.insert("Y", "Paper");
-    shapes_codes.insert("C", "Scissors");
-    shapes_codes.insert("Z", "Scissors");
-
-    let mut shapes_points = HashMap::new();
-    shapes_points.insert("Rock", 1);
-    shapes_points.insert("Paper", 2);
-    shapes_points.insert("Scissors", 3);
-
-    let mut shapes_counters = HashMap::new();
-    shapes_counters.insert("Rock", "Paper");
-    shapes_counters.insert("Paper", "Scissors");
-    shapes_counters.insert("Scissors", "Rock");
-
-    let mut shape_losers = HashMap::new();
-    shape_losers.insert("Paper", "Rock");
-    shape_losers.insert("Rock", "Scissors");
-    shape_losers.insert("Scissors", "Paper");
-
-    let mut total_score: u32 = 0;
-
-    for line in input.lines() {
-        let round = line.split(" ").collect::<Vec<&str>>();
-        let opponent = shapes_codes.get(round[0]).unwrap();
-        let counter = shapes_counters.get(opponent).unwrap();
-        let result_expected = match round[1] {
-            x if x == "X" => Round::Lose,
-            x if x == "Y" => Round::Draw,
-            _ => Round::Win
-        };
-
-        match result_expected {
-            Round::Win => { total_score += (6 + shapes_points.get(counter).unwrap()) }
-            Round::Draw => total_score += (3 + shapes_points.get(opponent).unwrap()),
-            Round::Lose => total_score += (0 + shapes_points.get(shape_losers.get(opponent).unwrap()).unwrap()),
-        }
-    }
-    Some(total_score)
+fn analyze(game: &Game, guide: &str) -> Result<(), advent_of_code::day02::GameError> {
+    println!("score as shapes:          {}", game.score_shapes(guide)?);
+    println!("score as outcomes:        {}", game.score_outcomes(guide)?);
+    println!("best possible score:      {}", game.best_score(guide)?);
+    println!(
+        "expected against random:  {:.1} as shapes, {:.1} as outcomes",
+        game.expected_score(guide, Column::Shape)?,
+        game.expected_score(guide, Column::Outcome)?
+    );
+    let mapping = game.best_mapping(guide)?;
+    println!(
+        "best mapping:             {} ({})",
+        game.describe(&mapping),
+        mapping.score
+    );
+    Ok(())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+    let args = parse_args();
+    if !args.analyze && args.game.is_none() {
+        // the parts aren't solved on an invalid guide, say why
+        let game = Game::rock_paper_scissors();
+        for input in advent_of_code::inputs::all(2) {
+            if let Err(e) = game.score_shapes(&input.text) {
+                eprintln!("Invalid input {}: {}", input.name, e);
+            }
+        }
+        advent_of_code::inputs::solve_all(2, part_one, part_two);
+        return;
     }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+    let game = args.game.unwrap_or_else(Game::rock_paper_scissors);
+    // answers differ from the puzzle's with another game, so they aren't checked
+    for input in advent_of_code::inputs::all(2) {
+        println!("── {} ──", input.name);
+        if args.analyze {
+            if let Err(e) = analyze(&game, &input.text) {
+                eprintln!("Invalid input {}: {}", input.name, e);
+                std::process::exit(1);
+            }
+        } else {
+            advent_of_code::run_part(1, |guide| game.score_shapes(guide).ok(), &input.text);
+            advent_of_code::run_part(2, |guide| game.score_outcomes(guide).ok(), &input.text);
+        }
     }
 }
@@ -0,0 +1,55 @@
+/*
+ * Prints a random puzzle input for a day, see `advent_of_code::generators`.
+ * example: `cargo gen 7 --size 1000 --seed 42 > src/inputs/07.txt`
+ */
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use advent_of_code::generators;
+use advent_of_code::helpers::rng::Rng;
+
+struct Args {
+    day: u8,
+    size: usize,
+    seed: Option<u64>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        size: args.opt_value_from_str(["-s", "--size"])?.unwrap_or(100),
+        seed: args.opt_value_from_str("--seed")?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Failed to process arguments: {}. example: `cargo gen 7 --size 1000 --seed 42`",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let generator = match generators::generator(args.day) {
+        Some(generator) => generator,
+        None => {
+            eprintln!("No generator for day {}.", args.day);
+            process::exit(1);
+        }
+    };
+
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    // on stderr so that stdout can be redirected to an input file
+    eprintln!("day {}, size {}, seed {}", args.day, args.size, seed);
+    println!("{}", generator(&mut Rng::new(seed), args.size));
+}
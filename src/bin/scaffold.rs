@@ -3,7 +3,7 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
@@ -16,30 +16,40 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = crate::read_file("examples", DAY);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = crate::read_file("examples", DAY);
         assert_eq!(part_two(&input), None);
     }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(DAY, part_one, part_two);
+    }
 }
 "###;
 
+const BIN_TEMPLATE: &str = r###"use advent_of_code::dayPADDED::{part_one, part_two};
+
+fn main() {
+    advent_of_code::inputs::solve_all(DAY, part_one, part_two);
+}
+"###;
+
+const LIB_PATH: &str = "src/lib.rs";
+
+const REGISTRY_PATH: &str = "src/registry.rs";
+
 fn parse_args() -> Result<u8, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     args.free_from_str()
@@ -50,7 +60,55 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Adds `pub mod dayNN;` to the library, keeping the module declarations sorted.
+fn register_module(day_padded: &str) -> Result<(), std::io::Error> {
+    let lib = fs::read_to_string(LIB_PATH)?;
+    let declaration = format!("pub mod day{};", day_padded);
+    let mut lines: Vec<&str> = lib.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Ok(());
+    }
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod "))
+        .collect();
+    let position = modules
+        .iter()
+        .find(|&&i| lines[i] > declaration.as_str())
+        .or(modules.last().map(|last| last + 1).as_ref())
+        .copied()
+        .unwrap_or(0);
+    lines.insert(position, &declaration);
+    fs::write(LIB_PATH, lines.join("\n") + "\n")
+}
+
+/// Adds `solution!(N, dayNN),` to the table of `advent_of_code::registry`, in order of days.
+fn register_solution(day: u8, day_padded: &str) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(REGISTRY_PATH)?;
+    let entry = format!("    solution!({}, day{}),", day, day_padded);
+    let mut lines: Vec<&str> = registry.lines().collect();
+    if lines.contains(&entry.as_str()) {
+        return Ok(());
+    }
+    let Some(start) = lines.iter().position(|line| line.starts_with("pub static SOLUTIONS")) else {
+        return Err(std::io::Error::other("no SOLUTIONS table"));
+    };
+    let end = start + lines[start..].iter().position(|&line| line == "];").unwrap_or(0);
+    let position = (start + 1..end)
+        .find(|&i| {
+            let registered = lines[i].trim_start_matches("    solution!(");
+            let registered: Option<u8> = registered.split(',').next().and_then(|d| d.parse().ok());
+            registered.is_some_and(|registered| registered > day)
+        })
+        .unwrap_or(end);
+    lines.insert(position, &entry);
+    fs::write(REGISTRY_PATH, lines.join("\n") + "\n")
 }
 
 fn main() {
@@ -66,7 +124,8 @@ fn main() {
 
     let input_path = format!("src/inputs/{}.txt", day_padded);
     let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let module_path = format!("src/day{}.rs", day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -86,6 +145,53 @@ fn main() {
         }
     }
 
+    match register_module(&day_padded) {
+        Ok(_) => {
+            println!(
+                "Registered module \"day{}\" in \"{}\"",
+                &day_padded, LIB_PATH
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match register_solution(day, &day_padded) {
+        Ok(_) => {
+            println!(
+                "Registered solution \"day{}\" in \"{}\"",
+                &day_padded, REGISTRY_PATH
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to register solution: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let bin = BIN_TEMPLATE
+        .replace("PADDED", &day_padded)
+        .replace("DAY", &day.to_string());
+    match file.write_all(bin.as_bytes()) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
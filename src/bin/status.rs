@@ -0,0 +1,175 @@
+/*
+ * Prints an overview of every day: solution, input and example files, tests, recorded
+ * answers and runtime on the default input.
+ * example: `cargo status`, or `cargo status --no-tests --no-run` for a quick look at the files.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+use advent_of_code::{registry, Answer, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Runtimes measured by previous runs, shown with `--no-run`.
+const TIMINGS_PATH: &str = "target/status-timings.txt";
+
+struct Args {
+    tests: bool,
+    run: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        tests: !args.contains("--no-tests"),
+        run: !args.contains("--no-run"),
+    })
+}
+
+fn file_status(path: &Path) -> &'static str {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() > 0 => "ok",
+        Ok(_) => "empty",
+        Err(_) => "missing",
+    }
+}
+
+/// The output of a cargo command and whether it succeeded. A day that fails on some of its
+/// inputs (see `advent_of_code::inputs`) still prints the answers of the others.
+fn cargo(args: &[&str]) -> Option<(bool, String)> {
+    let output = Command::new("cargo")
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    Some((output.status.success(), stdout))
+}
+
+/// The part of a day's output about its default input. With several inputs, the answers to
+/// each one follow a `── name ──` header, the default input first.
+fn default_input_output(output: &str) -> String {
+    output
+        .lines()
+        .enumerate()
+        .take_while(|&(index, line)| index == 0 || !line.contains("── "))
+        .map(|(_, line)| format!("{}\n", line))
+        .collect()
+}
+
+/// How the answer to a part compares to the recorded one, `None` if the day wasn't run.
+fn answer_status(answer: Option<&Option<Answer>>, recorded: &Option<Answer>) -> &'static str {
+    match (answer, recorded) {
+        (Some(Some(answer)), Some(recorded)) if answer == recorded => "✓",
+        (Some(Some(_)), Some(_)) => "✗",
+        (Some(None), _) => "-",
+        (_, Some(_)) => "✓?",
+        _ => "?",
+    }
+}
+
+fn read_timings() -> BTreeMap<u8, f64> {
+    fs::read_to_string(TIMINGS_PATH)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (day, time) = line.split_once(' ')?;
+            Some((day.parse().ok()?, time.parse().ok()?))
+        })
+        .collect()
+}
+
+fn write_timings(timings: &BTreeMap<u8, f64>) {
+    let lines: String = timings
+        .iter()
+        .map(|(day, time)| format!("{} {}\n", day, time))
+        .collect();
+    // the overview is still useful without them
+    let _ = fs::write(TIMINGS_PATH, lines);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Failed to process arguments: {}. example: `cargo status --no-tests`",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let src = PathBuf::from("src");
+    let mut timings = read_timings();
+    println!(
+        "{}Day  Solution  Input    Example  Tests   Answers  Runtime{}",
+        ANSI_BOLD, ANSI_RESET
+    );
+    for day in 1..=25 {
+        let padded = format!("{:02}", day);
+        let has_bin = src.join("bin").join(format!("{}.rs", padded)).exists();
+        // the library module of the day, which the tests and the answers are about
+        let has_solution = registry::solution(day).is_some();
+        let input = file_status(&src.join("inputs").join(format!("{}.txt", padded)));
+        let example = file_status(&src.join("examples").join(format!("{}.txt", padded)));
+
+        let tests = match (has_solution, args.tests) {
+            (false, _) => "-",
+            (true, false) => "skipped",
+            (true, true) => match cargo(&["test", "-q", "--lib", &format!("day{}::", padded)]) {
+                Some((true, _)) => "pass",
+                _ => "FAIL",
+            },
+        };
+
+        let recorded = advent_of_code::answer::recorded(day);
+        // the inputs of team members come after the default one, and aren't measured
+        let output = match (has_bin && has_solution && input == "ok", args.run) {
+            (true, true) => cargo(&["run", "-q", "--release", "--bin", &padded])
+                .map(|(_, output)| default_input_output(&output)),
+            _ => None,
+        };
+        if let Some(output) = &output {
+            timings.insert(day, advent_of_code::parse_exec_time(output));
+        }
+        let answers = output.as_deref().map(advent_of_code::parse_answers);
+        let answers = match has_solution {
+            false => "-".to_string(),
+            true => recorded
+                .iter()
+                .enumerate()
+                .map(|(part, recorded)| {
+                    let answer = answers.as_ref().and_then(|answers| answers.get(part));
+                    answer_status(answer, recorded)
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+
+        let runtime = match timings.get(&day) {
+            Some(time) if has_solution => format!("{:.2}ms", time),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:<4} {:<9} {:<8} {:<8} {:<7} {:<8} {}",
+            padded,
+            if has_bin { "ok" } else { "missing" },
+            input,
+            example,
+            tests,
+            answers,
+            runtime
+        );
+    }
+    write_timings(&timings);
+
+    println!(
+        "{}answers compared to src/answers/NN.txt: ✓ match, ✗ differ, - not solved, ? not recorded, ✓? not run{}",
+        ANSI_ITALIC, ANSI_RESET
+    );
+    println!(
+        "{}answers and runtime are for the default input src/inputs/NN.txt only{}",
+        ANSI_ITALIC, ANSI_RESET
+    );
+}
@@ -0,0 +1,405 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+// Dumb solution - just build a vector, sort it and return the last elements.
+// Kept as a reference for the differential tests.
+#[cfg(test)]
+fn sorted_weights(input: &str) -> Vec<u64> {
+    let groups = input.split("\n\n");
+    let mut weights: Vec<u64> = Vec::new();
+    for group in groups {
+        let lines = group.split('\n');
+        let mut calories = 0;
+        for line in lines {
+            calories += line.parse::<u64>().unwrap_or(0);
+        }
+        weights.push(calories);
+    }
+    weights.sort();
+    weights
+}
+
+#[cfg(test)]
+fn part_one_naive(input: &str) -> Option<u64> {
+    sorted_weights(input).last().copied()
+}
+
+#[cfg(test)]
+fn part_two_naive(input: &str) -> Option<u64> {
+    Some(sorted_weights(input).iter().rev().take(3).sum())
+}
+
+/// A line that isn't a number of calories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElfParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    pub text: String,
+}
+
+impl Display for ElfParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: expected calories, got {:?}", self.line, self.text)
+    }
+}
+
+impl std::error::Error for ElfParseError {}
+
+fn parse_calories(line: &str, line_number: usize) -> Result<u32, ElfParseError> {
+    line.parse().map_err(|_| ElfParseError {
+        line: line_number,
+        text: line.to_string(),
+    })
+}
+
+/// The calories of every item an elf carries, and its position among the elves of the input,
+/// starting at 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn calories(&self) -> u64 {
+        self.items.iter().map(|&calories| calories as u64).sum()
+    }
+}
+
+/// Every elf of the input. Elves are separated by one or more empty lines, and every other
+/// line must be a number of calories.
+pub fn parse_elves(input: &str) -> Result<Vec<Elf>, ElfParseError> {
+    let mut elves: Vec<Elf> = Vec::new();
+    let mut items = Vec::new();
+    for (line_number, line) in (1..).zip(input.lines().map(str::trim_end).chain([""])) {
+        if line.is_empty() {
+            if !items.is_empty() {
+                let index = elves.len();
+                elves.push(Elf {
+                    index,
+                    items: std::mem::take(&mut items),
+                });
+            }
+        } else {
+            items.push(parse_calories(line, line_number)?);
+        }
+    }
+    Ok(elves)
+}
+
+/// The total calories carried by an elf, and its position among the elves of the input,
+/// starting at 0. Totals are wider than the calories of a single item, so that an elf of a
+/// huge generated input can carry more than `u32::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub calories: u64,
+}
+
+impl Ord for ElfTotal {
+    /// More calories first, the first elf first on ties.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .calories
+            .cmp(&self.calories)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for ElfTotal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` elves carrying the most calories, most first, reading the input line by line so
+/// that it never has to fit in memory. Runs in O(n log k) for n elves. Lines that aren't
+/// calories fail with an `InvalidData` error wrapping an `ElfParseError`.
+pub fn top_k(mut reader: impl BufRead, k: usize) -> io::Result<Vec<ElfTotal>> {
+    // the worst of the best elves so far is on top, to be replaced by a better one
+    let mut best: BinaryHeap<ElfTotal> = BinaryHeap::with_capacity(k + 1);
+    let mut push = |elf: ElfTotal| {
+        best.push(elf);
+        if best.len() > k {
+            best.pop();
+        }
+    };
+
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut index = 0;
+    let mut current: Option<u64> = None;
+    loop {
+        line.clear();
+        let end = reader.read_line(&mut line)? == 0;
+        line_number += 1;
+        let line = line.trim_end();
+        // an empty line or the end of the input closes the current elf, if any
+        if end || line.is_empty() {
+            if let Some(calories) = current.take() {
+                push(ElfTotal { index, calories });
+                index += 1;
+            }
+            if end {
+                break;
+            }
+        } else {
+            let calories = parse_calories(line, line_number)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            *current.get_or_insert(0) += calories as u64;
+        }
+    }
+    Ok(best.into_sorted_vec())
+}
+
+/// `None` if a line isn't calories, see `top_k` for the error.
+fn top_sum(input: &str, k: usize) -> Option<u64> {
+    let best = top_k(input.as_bytes(), k).ok()?;
+    Some(best.iter().map(|elf| elf.calories).sum())
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    top_sum(input, 1)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    top_sum(input, 3)
+}
+
+/// A list of values, sorted, to describe how they are distributed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Distribution {
+    values: Vec<u64>,
+}
+
+impl Distribution {
+    pub fn new(mut values: Vec<u64>) -> Distribution {
+        values.sort_unstable();
+        Distribution { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.values.first().copied()
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.values.last().copied()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let sum: u128 = self.values.iter().map(|&value| value as u128).sum();
+        (!self.is_empty()).then(|| sum as f64 / self.len() as f64)
+    }
+
+    /// The value below which `p` percent of the values fall, interpolated between the two
+    /// closest values.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        let last = self.len().checked_sub(1)?;
+        let rank = p.clamp(0.0, 100.0) / 100.0 * last as f64;
+        let (below, above) = (
+            self.values[rank.floor() as usize] as f64,
+            self.values[rank.ceil() as usize] as f64,
+        );
+        Some(below + (above - below) * rank.fract())
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// How many values fall in each of at most `buckets` ranges of equal width, from the
+    /// minimum to the maximum, as `(first, last, count)`.
+    pub fn histogram(&self, buckets: usize) -> Vec<(u64, u64, usize)> {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return vec![];
+        };
+        let width = (max - min) / buckets.max(1) as u64 + 1;
+        let mut counts = vec![0; ((max - min) / width) as usize + 1];
+        for &value in &self.values {
+            counts[((value - min) / width) as usize] += 1;
+        }
+        (0..)
+            .zip(counts)
+            .map(|(bucket, count)| {
+                let first = min + bucket * width;
+                (first, (first + (width - 1)).min(max), count)
+            })
+            .collect()
+    }
+}
+
+impl Display for Distribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const BUCKETS: usize = 10;
+        const BAR_WIDTH: usize = 40;
+
+        let (Some(min), Some(max), Some(mean)) = (self.min(), self.max(), self.mean()) else {
+            return writeln!(f, "  no values");
+        };
+        writeln!(f, "  min {}, max {}, mean {:.1}", min, max, mean)?;
+        let percentiles = [10.0, 25.0, 50.0, 75.0, 90.0, 99.0]
+            .map(|p| format!("p{} {:.1}", p, self.percentile(p).unwrap_or_default()));
+        writeln!(f, "  {}", percentiles.join(", "))?;
+
+        let histogram = self.histogram(BUCKETS);
+        let most = histogram.iter().map(|&(_, _, count)| count).max().unwrap_or(1);
+        let label_width = histogram
+            .iter()
+            .map(|(first, last, _)| format!("{}..={}", first, last).len())
+            .max()
+            .unwrap_or(0);
+        for (first, last, count) in histogram {
+            let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+            let label = format!("{}..={}", first, last);
+            writeln!(
+                f,
+                "  {:>width$}  {:<BAR_WIDTH$} {}",
+                label,
+                bar,
+                count,
+                width = label_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes the calories and the number of items of the elves, e.g. to check that a
+/// generated input looks like a real one.
+pub fn report(elves: &[Elf]) -> String {
+    let calories = Distribution::new(elves.iter().map(Elf::calories).collect());
+    let items = Distribution::new(elves.iter().map(|elf| elf.items.len() as u64).collect());
+    format!(
+        "{} elves, {} items\n\ncalories per elf:\n{}\nitems per elf:\n{}",
+        elves.len(),
+        elves.iter().map(|elf| elf.items.len()).sum::<usize>(),
+        calories,
+        items
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use crate::helpers::differential::Differential;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 1);
+        assert_eq!(part_one(&input), Some(60));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 1);
+        assert_eq!(part_two(&input), Some(120));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let input = crate::read_file("examples", 1);
+        let best = top_k(input.as_bytes(), 2).unwrap();
+        assert_eq!(
+            best,
+            vec![
+                ElfTotal { index: 1, calories: 60 },
+                ElfTotal { index: 0, calories: 30 },
+            ]
+        );
+        assert_eq!(top_k(input.as_bytes(), 0).unwrap(), vec![]);
+
+        let best = top_k("1\n\n\n5\n2\n\n7\n\n7\n".as_bytes(), 10).unwrap();
+        assert_eq!(
+            best,
+            vec![
+                ElfTotal { index: 1, calories: 7 },
+                ElfTotal { index: 2, calories: 7 },
+                ElfTotal { index: 3, calories: 7 },
+                ElfTotal { index: 0, calories: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_elves() {
+        let elves = parse_elves("1000\n2000\n\n\n3000\n").unwrap();
+        assert_eq!(
+            elves,
+            vec![
+                Elf { index: 0, items: vec![1000, 2000] },
+                Elf { index: 1, items: vec![3000] },
+            ]
+        );
+        assert_eq!(elves[0].calories(), 3000);
+
+        let error = parse_elves("1000\n\n20OO\n").unwrap_err();
+        assert_eq!(error, ElfParseError { line: 3, text: "20OO".to_string() });
+        assert_eq!(error.to_string(), "line 3: expected calories, got \"20OO\"");
+        let error = top_k("1000\n-5\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "line 2: expected calories, got \"-5\"");
+        assert_eq!(part_one("1000\n-5\n"), None);
+    }
+
+    #[test]
+    fn test_totals_beyond_u32() {
+        let input = format!("{max}\n{max}\n\n1\n\n{max}\n", max = u32::MAX);
+        assert_eq!(part_one(&input), Some(2 * u32::MAX as u64));
+        assert_eq!(part_two(&input), Some(3 * u32::MAX as u64 + 1));
+        let elves = parse_elves(&input).unwrap();
+        assert_eq!(elves[0].calories(), 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let distribution = Distribution::new(vec![7, 1, 3, 5]);
+        assert_eq!((distribution.min(), distribution.max()), (Some(1), Some(7)));
+        assert_eq!(distribution.mean(), Some(4.0));
+        assert_eq!(distribution.median(), Some(4.0));
+        assert_eq!(distribution.percentile(0.0), Some(1.0));
+        assert_eq!(distribution.percentile(100.0), Some(7.0));
+        assert_eq!(
+            distribution.histogram(3),
+            vec![(1, 3, 2), (4, 6, 1), (7, 7, 1)]
+        );
+        assert_eq!(Distribution::new(vec![]).median(), None);
+        assert_eq!(Distribution::new(vec![]).histogram(10), vec![]);
+    }
+
+    #[test]
+    fn test_report() {
+        let input = crate::read_file("examples", 1);
+        let elves = parse_elves(&input).unwrap();
+        crate::assert_snapshot!(1, "report", report(&elves));
+    }
+
+    #[test]
+    fn test_differential() {
+        let report = Differential::new(generators::day01)
+            .implementation("sort", part_one_naive)
+            .implementation("streaming", part_one)
+            .assert_agree();
+        println!("{}", report);
+        let report = Differential::new(generators::day01)
+            .implementation("sort", part_two_naive)
+            .implementation("streaming", part_two)
+            .assert_agree();
+        println!("{}", report);
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(1, part_one, part_two);
+    }
+}
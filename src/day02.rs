@@ -0,0 +1,718 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Round {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        let description = match *self {
+            Round::Win => "Won",
+            Round::Lose => "Lost",
+            _ => "Draw"
+        };
+        f.write_str(description)
+    }
+}
+
+/// The game of the puzzle. Definitions have one statement per line, `#` starts a comment:
+///
+/// - `shape <name> <points> <opponent code> <my code>`: a shape, the points it is worth and the
+///   letters standing for it in the first and second column of the strategy guide;
+/// - `cycle <name>...`: each listed shape beats the following half of the list, wrapping around
+///   (with an even number of shapes, opposite shapes draw);
+/// - `beats <name> <name>...`: the first shape beats the other ones;
+/// - `outcomes <lose> <draw> <win>`: the letters of the second column read as outcomes;
+/// - `scores <lose> <draw> <win>`: the points of each outcome, `0 3 6` by default.
+///
+/// Shapes that don't beat each other draw.
+pub const ROCK_PAPER_SCISSORS: &str = "\
+shape Rock 1 A X
+shape Paper 2 B Y
+shape Scissors 3 C Z
+cycle Rock Scissors Paper
+outcomes X Y Z
+";
+
+/// Rock crushes scissors and lizard, scissors cut paper and decapitate lizard, paper covers
+/// rock and disproves Spock, lizard poisons Spock and eats paper, Spock smashes scissors and
+/// vaporizes rock.
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "\
+shape Rock 1 A V
+shape Paper 2 B W
+shape Scissors 3 C X
+shape Lizard 4 D Y
+shape Spock 5 E Z
+cycle Rock Scissors Lizard Paper Spock
+outcomes X Y Z
+";
+
+/// Each weapon beats the three that follow it.
+pub const ROCK_PAPER_SCISSORS_7: &str = "\
+shape Rock 1 A T
+shape Fire 2 B U
+shape Scissors 3 C V
+shape Sponge 4 D W
+shape Paper 5 E X
+shape Air 6 F Y
+shape Water 7 G Z
+cycle Rock Fire Scissors Sponge Paper Air Water
+outcomes X Y Z
+";
+
+/// What is wrong with a game definition or a strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameErrorKind {
+    /// A statement or a round that can't be read.
+    Syntax,
+    /// Two shapes with the same name or letter, or two outcomes with the same letter.
+    Duplicate,
+    /// A rule or a round that doesn't fit the game, like an unknown shape.
+    Invalid,
+    /// A statement the game needs that isn't there.
+    Missing,
+    /// A definition file that can't be read.
+    Io,
+}
+
+/// An invalid game definition or strategy guide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameError {
+    /// Line number, starting at 1, when the error is on a given line.
+    pub line: Option<usize>,
+    pub kind: GameErrorKind,
+    pub message: String,
+}
+
+impl GameError {
+    fn new(line: Option<usize>, kind: GameErrorKind, message: impl Into<String>) -> GameError {
+        GameError {
+            line,
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub points: u32,
+    /// The letter of the shape in the first column of the strategy guide.
+    pub opponent_code: char,
+    /// The letter of the shape in the second column of the strategy guide.
+    pub code: char,
+}
+
+/// Shapes, which ones beat which, and how rounds are scored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    /// `beats[a][b]` when shape `a` beats shape `b`.
+    beats: Vec<Vec<bool>>,
+    /// Letters of the second column read as a losing, drawing and winning outcome.
+    outcome_codes: [char; 3],
+    /// Points for losing, drawing and winning a round.
+    scores: [u32; 3],
+}
+
+fn parse_code(word: &str, line: usize) -> Result<char, GameError> {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(code), None) => Ok(code),
+        _ => Err(GameError::new(
+            Some(line),
+            GameErrorKind::Syntax,
+            format!("expected a single letter, got {:?}", word),
+        )),
+    }
+}
+
+fn parse_number(word: &str, line: usize) -> Result<u32, GameError> {
+    word.parse().map_err(|_| {
+        GameError::new(
+            Some(line),
+            GameErrorKind::Syntax,
+            format!("expected a number, got {:?}", word),
+        )
+    })
+}
+
+impl Game {
+    /// The game of the puzzle.
+    pub fn rock_paper_scissors() -> Game {
+        Game::parse(ROCK_PAPER_SCISSORS).unwrap()
+    }
+
+    /// Reads a game definition, see `ROCK_PAPER_SCISSORS` for the format.
+    pub fn parse(definition: &str) -> Result<Game, GameError> {
+        let mut shapes: Vec<Shape> = Vec::new();
+        // pairs of shape names, the first one beating the second one, and their line
+        let mut wins: Vec<(String, String, usize)> = Vec::new();
+        let mut outcome_codes = None;
+        let mut scores = [0, 3, 6];
+
+        for (line_number, line) in (1..).zip(definition.lines()) {
+            let line = line.split('#').next().unwrap_or_default();
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let wrong_length = || {
+                GameError::new(
+                    Some(line_number),
+                    GameErrorKind::Syntax,
+                    format!("wrong number of values for `{}`", words[0]),
+                )
+            };
+            match words.as_slice() {
+                [] => {}
+                ["shape", name, points, opponent_code, code] => {
+                    let shape = Shape {
+                        name: name.to_string(),
+                        points: parse_number(points, line_number)?,
+                        opponent_code: parse_code(opponent_code, line_number)?,
+                        code: parse_code(code, line_number)?,
+                    };
+                    let duplicate = shapes.iter().find_map(|known| {
+                        if known.name == shape.name {
+                            Some(format!("shape {} is already defined", shape.name))
+                        } else if known.opponent_code == shape.opponent_code
+                            || known.code == shape.code
+                        {
+                            Some(format!("{} and {} have the same code", known.name, shape.name))
+                        } else {
+                            None
+                        }
+                    });
+                    if let Some(message) = duplicate {
+                        return Err(GameError::new(
+                            Some(line_number),
+                            GameErrorKind::Duplicate,
+                            message,
+                        ));
+                    }
+                    shapes.push(shape);
+                }
+                ["shape", ..] => return Err(wrong_length()),
+                ["cycle", names @ ..] => {
+                    for (i, winner) in names.iter().enumerate() {
+                        for step in 1..=(names.len() - 1) / 2 {
+                            let loser = names[(i + step) % names.len()];
+                            wins.push((winner.to_string(), loser.to_string(), line_number));
+                        }
+                    }
+                }
+                ["beats", winner, losers @ ..] if !losers.is_empty() => {
+                    for loser in losers {
+                        wins.push((winner.to_string(), loser.to_string(), line_number));
+                    }
+                }
+                ["beats", ..] => return Err(wrong_length()),
+                ["outcomes", lose, draw, win] => {
+                    let codes = [
+                        parse_code(lose, line_number)?,
+                        parse_code(draw, line_number)?,
+                        parse_code(win, line_number)?,
+                    ];
+                    if codes.iter().all_unique() {
+                        outcome_codes = Some(codes);
+                    } else {
+                        return Err(GameError::new(
+                            Some(line_number),
+                            GameErrorKind::Duplicate,
+                            "outcomes have the same code",
+                        ));
+                    }
+                }
+                ["outcomes", ..] => return Err(wrong_length()),
+                ["scores", lose, draw, win] => {
+                    scores = [
+                        parse_number(lose, line_number)?,
+                        parse_number(draw, line_number)?,
+                        parse_number(win, line_number)?,
+                    ];
+                }
+                ["scores", ..] => return Err(wrong_length()),
+                [statement, ..] => {
+                    return Err(GameError::new(
+                        Some(line_number),
+                        GameErrorKind::Syntax,
+                        format!("unknown statement `{}`", statement),
+                    ))
+                }
+            }
+        }
+
+        if shapes.is_empty() {
+            return Err(GameError::new(None, GameErrorKind::Missing, "no shapes"));
+        }
+        let mut beats = vec![vec![false; shapes.len()]; shapes.len()];
+        for (winner, loser, line_number) in wins {
+            let index = |name: &str| {
+                shapes
+                    .iter()
+                    .position(|shape| shape.name == name)
+                    .ok_or_else(|| {
+                        GameError::new(
+                            Some(line_number),
+                            GameErrorKind::Invalid,
+                            format!("unknown shape {}", name),
+                        )
+                    })
+            };
+            let (winner_index, loser_index) = (index(&winner)?, index(&loser)?);
+            if winner_index == loser_index || beats[loser_index][winner_index] {
+                return Err(GameError::new(
+                    Some(line_number),
+                    GameErrorKind::Invalid,
+                    format!("{} can't beat {}", winner, loser),
+                ));
+            }
+            beats[winner_index][loser_index] = true;
+        }
+        let Some(outcome_codes) = outcome_codes else {
+            return Err(GameError::new(
+                None,
+                GameErrorKind::Missing,
+                "missing `outcomes`",
+            ));
+        };
+        Ok(Game {
+            shapes,
+            beats,
+            outcome_codes,
+            scores,
+        })
+    }
+
+    /// Reads a game definition from a file.
+    pub fn load(path: &Path) -> Result<Game, GameError> {
+        let definition = std::fs::read_to_string(path).map_err(|e| {
+            let message = format!("could not read {}: {}", path.display(), e);
+            GameError::new(None, GameErrorKind::Io, message)
+        })?;
+        Game::parse(&definition)
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// The outcome of a round for the player of `me`, by shape index.
+    pub fn round(&self, opponent: usize, me: usize) -> Round {
+        match (self.beats[me][opponent], self.beats[opponent][me]) {
+            (true, _) => Round::Win,
+            (_, true) => Round::Lose,
+            _ => Round::Draw,
+        }
+    }
+
+    pub fn outcome_score(&self, round: Round) -> u32 {
+        match round {
+            Round::Lose => self.scores[0],
+            Round::Draw => self.scores[1],
+            Round::Win => self.scores[2],
+        }
+    }
+
+    /// The score of the player of `me`: the points of the shape and of the outcome.
+    pub fn score(&self, opponent: usize, me: usize) -> u32 {
+        self.shapes[me].points + self.outcome_score(self.round(opponent, me))
+    }
+
+    /// The shape to play against `opponent` for the `round` outcome, the one worth the most
+    /// points when several would do.
+    pub fn shape_for(&self, opponent: usize, round: Round) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|&me| self.round(opponent, me) == round)
+            .max_by_key(|&me| (self.shapes[me].points, std::cmp::Reverse(me)))
+    }
+
+    fn shape_index(&self, code: char, code_of: fn(&Shape) -> char) -> Option<usize> {
+        self.shapes.iter().position(|shape| code_of(shape) == code)
+    }
+
+    fn outcome(&self, code: char) -> Option<Round> {
+        let index = self.outcome_codes.iter().position(|&known| known == code)?;
+        Some([Round::Lose, Round::Draw, Round::Win][index])
+    }
+
+    /// The rounds of a strategy guide as opponent shape and code of the second column.
+    fn read_guide<'a>(
+        &'a self,
+        guide: &'a str,
+    ) -> impl Iterator<Item = Result<(usize, char, usize), GameError>> + 'a {
+        (1..)
+            .zip(guide.lines())
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(line_number, line)| {
+                let error =
+                    |kind, message: String| GameError::new(Some(line_number), kind, message);
+                let words: Vec<&str> = line.split_whitespace().collect();
+                let [opponent, code] = words.as_slice() else {
+                    let message = format!("expected two letters, got {:?}", line);
+                    return Err(error(GameErrorKind::Syntax, message));
+                };
+                let opponent = parse_code(opponent, line_number)?;
+                let opponent = self
+                    .shape_index(opponent, |shape| shape.opponent_code)
+                    .ok_or_else(|| {
+                        error(GameErrorKind::Invalid, format!("unknown shape {:?}", opponent))
+                    })?;
+                Ok((opponent, parse_code(code, line_number)?, line_number))
+            })
+    }
+
+    /// The shape played for the second column `code` against `opponent`, read as `column`.
+    fn my_shape(
+        &self,
+        opponent: usize,
+        code: char,
+        column: Column,
+        line_number: usize,
+    ) -> Result<usize, GameError> {
+        let error =
+            |message: String| GameError::new(Some(line_number), GameErrorKind::Invalid, message);
+        match column {
+            Column::Shape => self
+                .shape_index(code, |shape| shape.code)
+                .ok_or_else(|| error(format!("unknown shape {:?}", code))),
+            Column::Outcome => {
+                let round = self
+                    .outcome(code)
+                    .ok_or_else(|| error(format!("unknown outcome {:?}", code)))?;
+                self.shape_for(opponent, round).ok_or_else(|| {
+                    error(format!("no shape {} against {}", round, self.shapes[opponent].name))
+                })
+            }
+        }
+    }
+
+    /// The total score of a strategy guide whose second column is read as `column`.
+    pub fn score_guide(&self, guide: &str, column: Column) -> Result<u32, GameError> {
+        self.read_guide(guide)
+            .map(|round| {
+                let (opponent, code, line_number) = round?;
+                Ok(self.score(opponent, self.my_shape(opponent, code, column, line_number)?))
+            })
+            .sum()
+    }
+
+    /// The total score of a strategy guide whose second column is the shape to play.
+    pub fn score_shapes(&self, guide: &str) -> Result<u32, GameError> {
+        self.score_guide(guide, Column::Shape)
+    }
+
+    /// The total score of a strategy guide whose second column is the outcome of the round.
+    pub fn score_outcomes(&self, guide: &str) -> Result<u32, GameError> {
+        self.score_guide(guide, Column::Outcome)
+    }
+
+    /// The highest score possible against the opponent shapes of the guide, playing the best
+    /// shape every round.
+    pub fn best_score(&self, guide: &str) -> Result<u32, GameError> {
+        self.read_guide(guide)
+            .map(|round| {
+                let (opponent, _, _) = round?;
+                let best = (0..self.shapes.len()).map(|me| self.score(opponent, me)).max();
+                Ok(best.unwrap_or_default())
+            })
+            .sum()
+    }
+
+    /// The average score of the guide, with its second column read as `column`, against an
+    /// opponent playing every shape with the same probability.
+    pub fn expected_score(&self, guide: &str, column: Column) -> Result<f64, GameError> {
+        let shapes = self.shapes.len();
+        self.read_guide(guide)
+            .map(|round| {
+                let (_, code, line_number) = round?;
+                let total = (0..shapes)
+                    .map(|opponent| {
+                        let me = self.my_shape(opponent, code, column, line_number)?;
+                        Ok(self.score(opponent, me))
+                    })
+                    .sum::<Result<u32, GameError>>()?;
+                Ok(total as f64 / shapes as f64)
+            })
+            .sum()
+    }
+
+    /// The assignment of shapes to the letters of the second column that scores the most,
+    /// reading it as shapes, the puzzle's own first on ties. Games with more than
+    /// `MAX_MAPPED_SHAPES` shapes are rejected, the search taking 2^n steps.
+    pub fn best_mapping(&self, guide: &str) -> Result<Mapping, GameError> {
+        let shapes = self.shapes.len();
+        if shapes > MAX_MAPPED_SHAPES {
+            let message = format!(
+                "can't map {} shapes, at most {} are supported",
+                shapes, MAX_MAPPED_SHAPES
+            );
+            return Err(GameError::new(None, GameErrorKind::Invalid, message));
+        }
+        // rounds by opponent shape and letter, letters numbered like the shapes they stand for
+        let mut counts = vec![vec![0; shapes]; shapes];
+        for round in self.read_guide(guide) {
+            let (opponent, code, line_number) = round?;
+            counts[opponent][self.my_shape(opponent, code, Column::Shape, line_number)?] += 1;
+        }
+        // the score of the rounds with the letter `code` when it stands for `shape`
+        let gain = |code: usize, shape: usize| -> u32 {
+            (0..shapes)
+                .map(|opponent| counts[opponent][code] * self.score(opponent, shape))
+                .sum()
+        };
+
+        // `rest[used]`: the best score of the letters after the first `used.count_ones()`,
+        // which stand for the shapes in `used`; supersets come first
+        let mut rest = vec![0; 1 << shapes];
+        for used in (0..(1 << shapes) - 1).rev() {
+            let code = (used as u32).count_ones() as usize;
+            rest[used] = (0..shapes)
+                .filter(|shape| used & 1 << shape == 0)
+                .map(|shape| gain(code, shape) + rest[used | 1 << shape])
+                .max()
+                .unwrap_or_default();
+        }
+        // the first shape that keeps the best score, for each letter in turn
+        let mut used = 0;
+        let mut played = Vec::with_capacity(shapes);
+        for code in 0..shapes {
+            let shape = (0..shapes)
+                .find(|&shape| {
+                    used & 1 << shape == 0
+                        && gain(code, shape) + rest[used | 1 << shape] == rest[used]
+                })
+                .unwrap();
+            used |= 1 << shape;
+            played.push(shape);
+        }
+        Ok(Mapping {
+            played,
+            score: rest[0],
+        })
+    }
+
+    /// Describes `mapping`, e.g. `X: Rock, Y: Paper, Z: Scissors`.
+    pub fn describe(&self, mapping: &Mapping) -> String {
+        self.shapes
+            .iter()
+            .zip(&mapping.played)
+            .map(|(shape, &played)| format!("{}: {}", shape.code, self.shapes[played].name))
+            .join(", ")
+    }
+}
+
+/// How the second column of a strategy guide is read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// The shape to play, as in part 1.
+    Shape,
+    /// The outcome of the round, as in part 2.
+    Outcome,
+}
+
+/// The most shapes `Game::best_mapping` accepts.
+pub const MAX_MAPPED_SHAPES: usize = 16;
+
+/// Which shape is played for each letter of the second column, and the resulting score.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Mapping {
+    /// The shape played for the letter of each shape, by shape index.
+    pub played: Vec<usize>,
+    pub score: u32,
+}
+
+/// `None` if the strategy guide is invalid, see `Game::score_shapes` for the error.
+pub fn part_one(input: &str) -> Option<u32> {
+    Game::rock_paper_scissors().score_shapes(input).ok()
+}
+
+/// `None` if the strategy guide is invalid, see `Game::score_outcomes` for the error.
+pub fn part_two(input: &str) -> Option<u32> {
+    Game::rock_paper_scissors().score_outcomes(input).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2);
+        assert_eq!(part_one(&input), Some(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2);
+        assert_eq!(part_two(&input), Some(12));
+    }
+
+    #[test]
+    fn test_games() {
+        for definition in [
+            ROCK_PAPER_SCISSORS,
+            ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+            ROCK_PAPER_SCISSORS_7,
+        ] {
+            let game = Game::parse(definition).unwrap();
+            let n = game.shapes().len();
+            // every shape beats and loses to half of the others
+            for me in 0..n {
+                let wins = (0..n).filter(|&other| game.round(other, me) == Round::Win);
+                let losses = (0..n).filter(|&other| game.round(other, me) == Round::Lose);
+                assert_eq!((wins.count(), losses.count()), ((n - 1) / 2, (n - 1) / 2));
+                assert_eq!(game.round(me, me), Round::Draw);
+            }
+        }
+
+        let game = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap();
+        let index = |name: &str| game.shapes().iter().position(|s| s.name == name).unwrap();
+        assert_eq!(game.round(index("Spock"), index("Lizard")), Round::Win);
+        assert_eq!(game.round(index("Rock"), index("Spock")), Round::Win);
+        assert_eq!(game.round(index("Paper"), index("Scissors")), Round::Win);
+        // Spock (E) against Lizard (Y) wins with 4 + 6, Rock (A) against Paper (W) 2 + 6
+        assert_eq!(game.score_shapes("E Y\nA W"), Ok(18));
+        // a win against Rock is Paper or Spock, the latter is worth more
+        assert_eq!(game.score_outcomes("A Z"), Ok(11));
+    }
+
+    #[test]
+    fn test_explicit_game() {
+        let game = Game::parse(
+            "# odd one out
+            shape Left 1 A X
+            shape Right 2 B Y
+            shape Up 10 C Z
+            beats Left Right
+            outcomes L D W
+            scores 1 2 4",
+        )
+        .unwrap();
+        assert_eq!(game.round(1, 2), Round::Draw);
+        assert_eq!(game.score_shapes("B X\nA Z"), Ok(5 + 12));
+        assert_eq!(game.score_outcomes("A L\nC D"), Ok(3 + 12));
+        assert_eq!(
+            game.score_outcomes("A W").unwrap_err().to_string(),
+            "line 1: no shape Won against Left"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |definition: &str| Game::parse(definition).unwrap_err().to_string();
+        let kind = |definition: &str| Game::parse(definition).unwrap_err().kind;
+        assert_eq!(kind("shape Rock 1 A X\nshape Rock 2 B Y"), GameErrorKind::Duplicate);
+        assert_eq!(kind("shape Rock 1 A X\nshape Paper 2 B X"), GameErrorKind::Duplicate);
+        assert_eq!(kind("shape Rock 1 A X\noutcomes X X Z"), GameErrorKind::Duplicate);
+        assert_eq!(kind("shape Rock 1 A X"), GameErrorKind::Missing);
+        assert_eq!(
+            error("shape Rock 1 A X\nshape Paper 2 A Y"),
+            "line 2: Rock and Paper have the same code"
+        );
+        assert_eq!(error("shape Rock one A X"), "line 1: expected a number, got \"one\"");
+        assert_eq!(
+            error("shape Rock 1 A X\nbeats Rock Paper"),
+            "line 2: unknown shape Paper"
+        );
+        assert_eq!(
+            error("shape A 1 A X\nshape B 2 B Y\nbeats A B\nbeats B A"),
+            "line 4: B can't beat A"
+        );
+        assert_eq!(error("shape Rock 1 A X"), "missing `outcomes`");
+        assert_eq!(
+            error("shape Rock 1 A X\nshape Paper 2 B Y\noutcomes X Y X"),
+            "line 3: outcomes have the same code"
+        );
+        assert_eq!(error("shapes Rock"), "line 1: unknown statement `shapes`");
+
+        let game = Game::rock_paper_scissors();
+        assert_eq!(
+            game.score_shapes("A Y\nD X").unwrap_err().to_string(),
+            "line 2: unknown shape 'D'"
+        );
+        assert_eq!(
+            game.score_outcomes("A Y\nB").unwrap_err().to_string(),
+            "line 2: expected two letters, got \"B\""
+        );
+        assert_eq!(part_one("A Y\nD X"), None);
+        assert_eq!(part_two("A Y\nB"), None);
+    }
+
+    #[test]
+    fn test_analysis() {
+        let input = crate::read_file("examples", 2);
+        let game = Game::rock_paper_scissors();
+        // Paper against Rock, Scissors against Paper, Rock against Scissors
+        assert_eq!(game.best_score(&input), Ok(8 + 9 + 7));
+        // the outcome is 3 on average whatever the shape, plus the points of Paper, Rock and
+        // Scissors; when playing for an outcome the average shape is worth 2
+        assert_eq!(game.expected_score(&input, Column::Shape), Ok(5.0 + 4.0 + 6.0));
+        assert_eq!(game.expected_score(&input, Column::Outcome), Ok(5.0 + 2.0 + 8.0));
+
+        let mapping = game.best_mapping(&input).unwrap();
+        assert_eq!(mapping, Mapping { played: vec![2, 1, 0], score: 24 });
+        assert_eq!(game.describe(&mapping), "X: Scissors, Y: Paper, Z: Rock");
+        // ties keep the puzzle's mapping
+        let mapping = game.best_mapping("A Y\nA Y").unwrap();
+        assert_eq!(mapping, Mapping { played: vec![0, 1, 2], score: 16 });
+    }
+
+    #[test]
+    fn test_best_mapping() {
+        use std::cmp::Reverse;
+
+        let game = Game::parse(ROCK_PAPER_SCISSORS_7).unwrap();
+        let guide = "A T\nB Z\nC V\nD W\nE X\nF Y\nG U\nC Z\nE T\nA Y";
+        let mapping = game.best_mapping(guide).unwrap();
+        // every permutation, the first one on ties
+        let rounds: Vec<(usize, usize)> = guide
+            .lines()
+            .map(|line| {
+                let line = line.as_bytes();
+                ((line[0] - b'A') as usize, (line[2] - b'T') as usize)
+            })
+            .collect();
+        let (score, Reverse(played)) = (0..7)
+            .permutations(7)
+            .map(|played| {
+                let score = rounds
+                    .iter()
+                    .map(|&(opponent, code)| game.score(opponent, played[code]))
+                    .sum::<u32>();
+                (score, Reverse(played))
+            })
+            .max()
+            .unwrap();
+        assert_eq!(mapping, Mapping { played, score });
+
+        let definition: String = (0..=MAX_MAPPED_SHAPES as u8)
+            .map(|i| format!("shape S{} 1 {} {}\n", i, (b'A' + i) as char, (b'a' + i) as char))
+            .chain(["outcomes a b c".to_string()])
+            .collect();
+        let error = Game::parse(&definition).unwrap().best_mapping("A a").unwrap_err();
+        assert_eq!(error.kind, GameErrorKind::Invalid);
+        assert_eq!(error.to_string(), "can't map 17 shapes, at most 16 are supported");
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(2, part_one, part_two);
+    }
+}
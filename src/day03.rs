@@ -0,0 +1,485 @@
+use std::fmt::Display;
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// A set of items, one bit per item: bit `priority - 1` for the item of that priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> Self {
+        ItemSet(0)
+    }
+
+    /// The items of `items`, ignoring anything that isn't a letter.
+    pub fn from_items(items: &str) -> Self {
+        items.chars().fold(ItemSet::new(), |mut set, item| {
+            set.insert(item);
+            set
+        })
+    }
+
+    /// Adds `item`, returning whether it is an item at all.
+    pub fn insert(&mut self, item: char) -> bool {
+        match get_char_priority(item) {
+            Some(priority) => {
+                self.0 |= 1 << (priority - 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        get_char_priority(item).is_some_and(|priority| self.0 & (1 << (priority - 1)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items, by increasing priority.
+    pub fn items(&self) -> impl Iterator<Item = char> {
+        let bits = self.0;
+        (1..=52)
+            .filter(move |priority| bits & (1 << (priority - 1)) != 0)
+            .filter_map(get_priority_char)
+    }
+
+    pub fn priority(&self) -> u32 {
+        (1..=52).filter(|priority| self.0 & (1 << (priority - 1)) != 0).sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = ItemSet;
+
+    fn sub(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+}
+
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.items().collect::<String>())
+    }
+}
+
+/// Items of one kind moved from a compartment to the other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    /// Compartment the items leave, 1 or 2.
+    pub from: i32,
+    pub to: i32,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} x {} from compartment {} to compartment {}",
+            self.count, self.item, self.from, self.to
+        )
+    }
+}
+
+/// An item that isn't a letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RucksackError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    pub item: char,
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {:?} is not an item", self.line, self.item)
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// The items of a compartment, and how many of each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Compartment {
+    items: ItemSet,
+    /// Count of the item of priority `p` at index `p - 1`.
+    counts: [usize; 52],
+}
+
+impl Compartment {
+    fn new() -> Self {
+        Compartment {
+            items: ItemSet::new(),
+            counts: [0; 52],
+        }
+    }
+
+    fn add(&mut self, item: char) -> bool {
+        let Some(priority) = get_char_priority(item) else {
+            return false;
+        };
+        self.items.insert(item);
+        self.counts[priority as usize - 1] += 1;
+        true
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.items
+    }
+
+    /// How many times `item` is in the compartment.
+    pub fn count(&self, item: char) -> usize {
+        get_char_priority(item).map_or(0, |priority| self.counts[priority as usize - 1])
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+pub struct Rucksack {
+    id: i32,
+    compartments: [Compartment; 2],
+    shared_items: ItemSet,
+}
+
+#[derive(Default)]
+pub struct ElfGroup {
+    rucksacks: Vec<Rucksack>,
+}
+
+impl Rucksack {
+    /// The rucksack of line `id` (from 0), its first half of items in compartment 1.
+    pub fn parse(id: i32, items: &str) -> Result<Self, RucksackError> {
+        let half = items.chars().count() / 2;
+        let mut compartments = [Compartment::new(); 2];
+        for (index, item) in items.chars().enumerate() {
+            if !compartments[usize::from(index >= half)].add(item) {
+                return Err(RucksackError { line: id as usize + 1, item });
+            }
+        }
+        Ok(Rucksack {
+            id,
+            compartments,
+            shared_items: compartments[0].items() & compartments[1].items(),
+        })
+    }
+
+    /// Compartment 1 or 2.
+    pub fn compartment(&self, compartment: i32) -> &Compartment {
+        match compartment {
+            1 => &self.compartments[0],
+            2 => &self.compartments[1],
+            _ => panic!("Invalid compartment number"),
+        }
+    }
+
+    pub fn shared_items(&self) -> ItemSet {
+        self.shared_items
+    }
+
+    pub fn calculate_priority(&self) -> u32 {
+        self.shared_items.priority()
+    }
+
+    /// The fewest item moves that leave no item in both compartments: every copy of a shared
+    /// item goes to the compartment that already has the most of them, the first one on ties.
+    /// Compartments may end up with different sizes.
+    pub fn plan_moves(&self) -> Vec<Move> {
+        let [compartment1, compartment2] = &self.compartments;
+        self.shared_items
+            .items()
+            .map(|item| {
+                let (count1, count2) = (compartment1.count(item), compartment2.count(item));
+                match count1 >= count2 {
+                    true => Move { item, count: count2, from: 2, to: 1 },
+                    false => Move { item, count: count1, from: 1, to: 2 },
+                }
+            })
+            .collect()
+    }
+
+    fn collect_compartiments(&self) -> ItemSet {
+        self.compartments[0].items() | self.compartments[1].items()
+    }
+}
+
+impl Display for Rucksack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [left, right] = &self.compartments;
+        write!(f, "Rucksack {} has {} items in compartment 1 and {} items in compartment 2", self.id, left.len(), right.len())
+    }
+}
+
+impl ElfGroup {
+    pub fn new() -> Self {
+        ElfGroup {
+            rucksacks: Vec::new(),
+        }
+    }
+
+    pub fn add_rucksack(&mut self, rucksack: Rucksack) {
+        self.rucksacks.push(rucksack);
+    }
+
+    /// The items carried by every elf of the group, whatever its size.
+    pub fn get_shared_items(&self) -> ItemSet {
+        self.rucksacks
+            .iter()
+            .map(Rucksack::collect_compartiments)
+            .reduce(|shared, items| shared & items)
+            .unwrap_or_default()
+    }
+
+    pub fn calculate_priority(&self) -> u32 {
+        self.get_shared_items().priority()
+    }
+
+    /// The items that could be the group's badge: every item the whole group carries. The
+    /// puzzle assumes there is exactly one.
+    pub fn badge_candidates(&self) -> ItemSet {
+        self.get_shared_items()
+    }
+}
+
+/// The moves making the compartments of every rucksack disjoint, and the badge candidates of
+/// every group.
+pub struct Reorganisation {
+    /// Moves by rucksack id, for rucksacks that need some.
+    pub moves: Vec<(i32, Vec<Move>)>,
+    /// Priority of the items that were in both compartments, which no longer are.
+    pub priority_saved: u32,
+    pub badge_candidates: Vec<ItemSet>,
+}
+
+impl Reorganisation {
+    pub fn new(input: &str, group_size: usize) -> Result<Self, RucksackError> {
+        let groups = elf_groups(input, group_size)?;
+        let rucksacks = groups.iter().flat_map(|group| &group.rucksacks);
+        Ok(Reorganisation {
+            moves: rucksacks
+                .clone()
+                .map(|rucksack| (rucksack.id, rucksack.plan_moves()))
+                .filter(|(_, moves)| !moves.is_empty())
+                .collect(),
+            priority_saved: rucksacks.map(Rucksack::calculate_priority).sum(),
+            badge_candidates: groups.iter().map(ElfGroup::badge_candidates).collect(),
+        })
+    }
+
+    /// How many items are moved in total.
+    pub fn items_moved(&self) -> usize {
+        self.moves
+            .iter()
+            .flat_map(|(_, moves)| moves)
+            .map(|item_move| item_move.count)
+            .sum()
+    }
+}
+
+impl Display for Reorganisation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (id, moves) in &self.moves {
+            let moves = moves.iter().map(Move::to_string).collect::<Vec<_>>();
+            writeln!(f, "rucksack {}: {}", id, moves.join(", "))?;
+        }
+        writeln!(
+            f,
+            "{} items moved in {} rucksacks, priority saved: {}",
+            self.items_moved(),
+            self.moves.len(),
+            self.priority_saved
+        )?;
+        for (index, candidates) in self.badge_candidates.iter().enumerate() {
+            match candidates.len() {
+                1 => continue,
+                0 => writeln!(f, "group {}: no badge", index)?,
+                _ => writeln!(f, "group {}: badge candidates {}", index, candidates)?,
+            }
+        }
+        let single = self.badge_candidates.iter().filter(|c| c.len() == 1).count();
+        write!(f, "{} of {} groups have a single badge", single, self.badge_candidates.len())
+    }
+}
+
+/// Priorities 1 to 26 for `a` to `z` and 27 to 52 for `A` to `Z`, `None` for anything else.
+pub fn get_char_priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item of a priority, the inverse of `get_char_priority`.
+pub fn get_priority_char(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// The rucksacks of the input in groups of `size` elves, the last one possibly smaller.
+pub fn elf_groups(input: &str, size: usize) -> Result<Vec<ElfGroup>, RucksackError> {
+    let mut elf_groups: Vec<ElfGroup> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if (index % size.max(1)) == 0 {
+            elf_groups.push(ElfGroup::new());
+        }
+        let rucksack = Rucksack::parse(index as i32, line)?;
+        if let Some(elf_group) = elf_groups.last_mut() {
+            elf_group.add_rucksack(rucksack);
+        }
+    }
+    Ok(elf_groups)
+}
+
+/// `None` if an item isn't a letter, see `Rucksack::parse` for the error.
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut rucksacks: Vec<Rucksack> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let rucksack = Rucksack::parse(index as i32, line).ok()?;
+        rucksacks.push(rucksack);
+    }
+
+    Some(rucksacks.iter().map(Rucksack::calculate_priority).sum())
+}
+
+/// `None` if an item isn't a letter.
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(elf_groups(input, 3).ok()?.iter().map(ElfGroup::calculate_priority).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 3);
+        assert_eq!(part_one(&input), Some(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 3);
+        assert_eq!(part_two(&input), Some(70));
+    }
+
+    #[test]
+    fn test_priorities() {
+        for priority in 1..=52 {
+            let item = get_priority_char(priority).unwrap();
+            assert_eq!(get_char_priority(item), Some(priority));
+        }
+        assert_eq!(get_char_priority('p'), Some(16));
+        assert_eq!(get_char_priority('L'), Some(38));
+        assert_eq!((get_char_priority('é'), get_char_priority('1')), (None, None));
+        assert_eq!((get_priority_char(0), get_priority_char(53)), (None, None));
+    }
+
+    #[test]
+    fn test_item_set() {
+        let left = ItemSet::from_items("vJrwpWtwJgWr");
+        let right = ItemSet::from_items("hcsFMMfFFhFp");
+        assert_eq!((left & right).to_string(), "p");
+        assert_eq!((left | right).len(), 14);
+        assert_eq!((left - right).to_string(), "grtvwJW");
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('?'));
+        assert_eq!(ItemSet::from_items("aZ").priority(), 1 + 52);
+        assert!(ItemSet::from_items("12 !").is_empty());
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        let input = crate::read_file("examples", 3);
+        let badges = |size| -> Vec<String> {
+            elf_groups(&input, size)
+                .unwrap()
+                .iter()
+                .map(|group| group.get_shared_items().to_string())
+                .collect()
+        };
+        assert_eq!(badges(3), vec!["r", "Z"]);
+        assert_eq!(badges(6), vec![""]);
+        assert_eq!(badges(2), vec!["frsFM", "qvwBT", "GJZ"]);
+    }
+
+    #[test]
+    fn test_reorganisation() {
+        let rucksack = Rucksack::parse(0, "ttgJtRGJQctTZtZT").unwrap();
+        assert_eq!(
+            rucksack.plan_moves(),
+            vec![Move { item: 't', count: 2, from: 2, to: 1 }]
+        );
+        let rucksack = Rucksack::parse(1, "aAAaaA").unwrap();
+        assert_eq!(
+            rucksack.plan_moves(),
+            vec![
+                Move { item: 'a', count: 1, from: 1, to: 2 },
+                Move { item: 'A', count: 1, from: 2, to: 1 },
+            ]
+        );
+
+        let input = crate::read_file("examples", 3);
+        let reorganisation = Reorganisation::new(&input, 3).unwrap();
+        assert_eq!(reorganisation.moves.len(), 6);
+        assert_eq!(reorganisation.items_moved(), 9);
+        assert_eq!(reorganisation.priority_saved, 157);
+        let badges = Reorganisation::new(&input, 2).unwrap().badge_candidates;
+        assert_eq!(badges.iter().map(ItemSet::len).collect::<Vec<_>>(), vec![5, 5, 3]);
+        assert_eq!(
+            Reorganisation::new(&input, 2).unwrap().to_string().lines().last(),
+            Some("0 of 3 groups have a single badge")
+        );
+    }
+
+    #[test]
+    fn test_invalid_items() {
+        let rucksack = Rucksack::parse(0, "abcAbd").unwrap();
+        assert_eq!(rucksack.compartment(1).count('b'), 1);
+        assert_eq!(rucksack.compartment(2).count('b'), 1);
+        assert_eq!(rucksack.compartment(2).len(), 3);
+        assert_eq!(rucksack.shared_items().to_string(), "b");
+
+        // a multibyte item in the middle of the line
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\nabcé€xyz\n";
+        let error = Rucksack::parse(1, "abcé€xyz").err();
+        assert_eq!(error, Some(RucksackError { line: 2, item: 'é' }));
+        assert_eq!(error.unwrap().to_string(), "line 2: 'é' is not an item");
+        assert_eq!((part_one(input), part_two(input)), (None, None));
+        assert!(Reorganisation::new("ab1b", 3).is_err());
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(3, part_one, part_two);
+    }
+}
@@ -0,0 +1,474 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::helpers::IntervalSet;
+
+/// How an assignment relates to another one, after Allen's interval algebra. Assignments are
+/// ranges of whole sections, so one meets the next when it ends on the section right before.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Before,
+    Meets,
+    Overlaps,
+    Starts,
+    During,
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 13] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::Overlaps,
+        Relation::Starts,
+        Relation::During,
+        Relation::Finishes,
+        Relation::Equals,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::StartedBy,
+        Relation::OverlappedBy,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    /// The relation of the second assignment to the first one.
+    pub fn inverse(self) -> Relation {
+        Relation::ALL[Relation::ALL.len() - 1 - self as usize]
+    }
+
+    /// Whether one of the assignments contains the other one.
+    pub fn is_containment(self) -> bool {
+        !self.is_disjoint() && !matches!(self, Relation::Overlaps | Relation::OverlappedBy)
+    }
+
+    /// Whether the assignments have no section in common.
+    pub fn is_disjoint(self) -> bool {
+        matches!(
+            self,
+            Relation::Before | Relation::Meets | Relation::MetBy | Relation::After
+        )
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Relation::Before => "before",
+            Relation::Meets => "meets",
+            Relation::Overlaps => "overlaps",
+            Relation::Starts => "starts",
+            Relation::During => "during",
+            Relation::Finishes => "finishes",
+            Relation::Equals => "equals",
+            Relation::FinishedBy => "finished by",
+            Relation::Contains => "contains",
+            Relation::StartedBy => "started by",
+            Relation::OverlappedBy => "overlapped by",
+            Relation::MetBy => "met by",
+            Relation::After => "after",
+        };
+        f.pad(description)
+    }
+}
+
+pub struct ElfPair {
+    /// Sections of each assignment, in `u64` so that an assignment may end on `u32::MAX`.
+    sections1: IntervalSet<u64>,
+    sections2: IntervalSet<u64>,
+}
+
+impl ElfPair {
+    pub fn new(assignment1: (u32, u32), assignment2: (u32, u32)) -> ElfPair {
+        ElfPair {
+            sections1: sections(assignment1),
+            sections2: sections(assignment2),
+        }
+    }
+
+    /// The relation of the first assignment to the second one.
+    pub fn relation(&self) -> Relation {
+        let bounds = |sections: &IntervalSet<u64>| sections.ranges().first().cloned();
+        let (range1, range2) = (bounds(&self.sections1), bounds(&self.sections2));
+        let (range1, range2) = (range1.unwrap_or_default(), range2.unwrap_or_default());
+        let subset = self.sections1.is_subset(&self.sections2);
+        let superset = self.sections2.is_subset(&self.sections1);
+        match () {
+            _ if self.sections1.is_disjoint(&self.sections2) => {
+                let meets = self.sections1.union(&self.sections2).ranges().len() == 1;
+                match (range1.start < range2.start, meets) {
+                    (true, false) => Relation::Before,
+                    (true, true) => Relation::Meets,
+                    (false, true) => Relation::MetBy,
+                    (false, false) => Relation::After,
+                }
+            }
+            _ if subset && superset => Relation::Equals,
+            _ if subset && range1.start == range2.start => Relation::Starts,
+            _ if subset && range1.end == range2.end => Relation::Finishes,
+            _ if subset => Relation::During,
+            _ if superset && range1.start == range2.start => Relation::StartedBy,
+            _ if superset && range1.end == range2.end => Relation::FinishedBy,
+            _ if superset => Relation::Contains,
+            _ if range1.start < range2.start => Relation::Overlaps,
+            _ => Relation::OverlappedBy,
+        }
+    }
+
+    pub fn are_assignements_subsets(&self) -> bool {
+        self.sections1.is_subset(&self.sections2) || self.sections2.is_subset(&self.sections1)
+    }
+
+    pub fn are_sets_disjoints(&self) -> bool {
+        self.sections1.is_disjoint(&self.sections2)
+    }
+}
+
+/// The sections of an assignment `start..=end`.
+fn sections((start, end): (u32, u32)) -> IntervalSet<u64> {
+    IntervalSet::from(IntervalSet::inclusive(start as u64, end as u64))
+}
+
+/// The two assignments of a line like `2-4,6-8`.
+pub fn parse_assignments(line: &str) -> ((u32, u32), (u32, u32)) {
+    let assignments = line.split(",").collect::<Vec<&str>>();
+    let assignment1 = assignments[0].split("-").collect::<Vec<&str>>();
+    let assignment2 = assignments[1].split("-").collect::<Vec<&str>>();
+    let assignment1 = (assignment1[0].parse::<u32>().unwrap(), assignment1[1].parse::<u32>().unwrap());
+    let assignment2 = (assignment2[0].parse::<u32>().unwrap(), assignment2[1].parse::<u32>().unwrap());
+    (assignment1, assignment2)
+}
+
+pub fn parse_pairs(input: &str) -> Vec<ElfPair> {
+    input
+        .lines()
+        .map(parse_assignments)
+        .map(|(assignment1, assignment2)| ElfPair::new(assignment1, assignment2))
+        .collect()
+}
+
+/// How many pairs of the input are in each relation, in the order of `Relation::ALL`.
+pub fn count_relations(input: &str) -> Vec<(Relation, usize)> {
+    let mut counts = [0; 13];
+    for elf_pair in parse_pairs(input) {
+        counts[elf_pair.relation() as usize] += 1;
+    }
+    Relation::ALL.into_iter().zip(counts).collect()
+}
+
+/// Sections `start..=end`, all assigned to the same number of elves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub start: u32,
+    pub end: u32,
+    pub elves: usize,
+}
+
+impl Segment {
+    pub fn sections(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+}
+
+/// How many elves every section is assigned to, from the first assigned section to the last.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Consecutive segments, each covered by a different number of elves than the previous one.
+    segments: Vec<Segment>,
+    /// The sections assigned to at least one elf.
+    assigned: IntervalSet<u64>,
+}
+
+impl Coverage {
+    /// Sweeps over the starts and ends of the assignments, in O(n log n) for n assignments.
+    pub fn new(assignments: &[(u32, u32)]) -> Coverage {
+        // elves joining and leaving at each position, ends being past the assignment
+        let mut events: Vec<(u64, i64)> = assignments
+            .iter()
+            .flat_map(|&(start, end)| [(start as u64, 1), (end as u64 + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut elves = 0_i64;
+        for (index, &(position, change)) in events.iter().enumerate() {
+            elves += change;
+            let Some(&(next, _)) = events.get(index + 1) else {
+                break;
+            };
+            if next == position {
+                continue;
+            }
+            let (start, end) = (position as u32, (next - 1) as u32);
+            match segments.last_mut() {
+                Some(last) if last.elves == elves as usize => last.end = end,
+                _ => segments.push(Segment { start, end, elves: elves as usize }),
+            }
+        }
+        let assigned = assignments
+            .iter()
+            .map(|&(start, end)| IntervalSet::inclusive(start as u64, end as u64))
+            .collect();
+        Coverage { segments, assigned }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// How many sections are assigned to exactly k elves, by increasing k, for every k that
+    /// happens. Sections assigned to no elf are only counted between the first and last one.
+    pub fn sections_by_elves(&self) -> Vec<(usize, u64)> {
+        let mut sections = BTreeMap::new();
+        for segment in &self.segments {
+            *sections.entry(segment.elves).or_insert(0) += segment.sections();
+        }
+        sections.into_iter().collect()
+    }
+
+    /// The first of the segments assigned to the most elves.
+    pub fn max_coverage(&self) -> Option<Segment> {
+        self.segments
+            .iter()
+            .rev()
+            .max_by_key(|segment| segment.elves)
+            .copied()
+    }
+
+    /// The sections assigned to no elf, between the first and last assigned sections.
+    pub fn gaps(&self) -> Vec<(u32, u32)> {
+        let ranges = self.assigned.ranges();
+        let (Some(first), Some(last)) = (ranges.first(), ranges.last()) else {
+            return Vec::new();
+        };
+        self.assigned
+            .gaps(first.start..last.end)
+            .into_iter()
+            .map(|gap| (gap.start as u32, (gap.end - 1) as u32))
+            .collect()
+    }
+
+    /// The assignments, by index, whose every section is also assigned to another elf: each of
+    /// them could be dropped alone without leaving a section uncovered.
+    pub fn redundant(&self, assignments: &[(u32, u32)]) -> Vec<usize> {
+        // how many segments covered by a single elf come before each segment
+        let single: Vec<usize> = std::iter::once(0)
+            .chain(self.segments.iter().scan(0, |count, segment| {
+                *count += usize::from(segment.elves <= 1);
+                Some(*count)
+            }))
+            .collect();
+        let segment_of = |section: u32| {
+            self.segments
+                .partition_point(|segment| segment.end < section)
+        };
+        (0..assignments.len())
+            .filter(|&index| {
+                let (start, end) = assignments[index];
+                single[segment_of(end) + 1] == single[segment_of(start)]
+            })
+            .collect()
+    }
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (Some(first), Some(last)) = (self.segments.first(), self.segments.last()) else {
+            return write!(f, "no assignments");
+        };
+        writeln!(f, "sections {} to {}", first.start, last.end)?;
+        for (elves, sections) in self.sections_by_elves() {
+            writeln!(f, "  {} sections assigned to {} elves", sections, elves)?;
+        }
+        if let Some(max) = self.max_coverage() {
+            writeln!(
+                f,
+                "at most {} elves, first on sections {} to {}",
+                max.elves, max.start, max.end
+            )?;
+        }
+        let gaps = self.gaps();
+        let gaps: Vec<String> = gaps
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect();
+        match gaps.is_empty() {
+            true => write!(f, "no gaps"),
+            false => write!(f, "gaps: {}", gaps.join(", ")),
+        }
+    }
+}
+
+/// Every assignment of the input, two per line.
+pub fn parse_all_assignments(input: &str) -> Vec<(u32, u32)> {
+    input
+        .lines()
+        .map(parse_assignments)
+        .flat_map(|(assignment1, assignment2)| [assignment1, assignment2])
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let pairs = parse_pairs(input);
+    Some(pairs.iter().filter(|pair| pair.are_assignements_subsets()).count() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let pairs = parse_pairs(input);
+    Some(pairs.iter().filter(|pair| !pair.are_sets_disjoints()).count() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use crate::helpers::rng::Rng;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_two(&input), Some(4));
+    }
+
+    #[test]
+    fn test_relations() {
+        let relation = |line: &str| {
+            let (assignment1, assignment2) = parse_assignments(line);
+            ElfPair::new(assignment1, assignment2).relation()
+        };
+        let cases = [
+            ("1-2,4-5", Relation::Before),
+            ("1-3,4-5", Relation::Meets),
+            ("1-4,4-5", Relation::Overlaps),
+            ("2-3,2-5", Relation::Starts),
+            ("3-3,2-5", Relation::During),
+            ("4-5,2-5", Relation::Finishes),
+            ("2-5,2-5", Relation::Equals),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(relation(line), expected, "{}", line);
+            let (first, second) = line.split_once(',').unwrap();
+            let inverse = relation(&format!("{},{}", second, first));
+            assert_eq!(inverse, expected.inverse(), "{}", line);
+            assert_eq!(inverse.inverse(), expected);
+        }
+        assert_eq!(Relation::Equals.inverse(), Relation::Equals);
+        assert_eq!(Relation::Contains.to_string(), "contains");
+    }
+
+    #[test]
+    fn test_relations_at_u32_max() {
+        let max = u32::MAX;
+        let relation = |assignment1, assignment2| ElfPair::new(assignment1, assignment2).relation();
+        assert_eq!(relation((max, max), (max, max)), Relation::Equals);
+        assert_eq!(relation((1, max), (max, max)), Relation::FinishedBy);
+        assert_eq!(relation((max - 1, max - 1), (max, max)), Relation::Meets);
+        assert_eq!(relation((max, max), (0, max - 1)), Relation::MetBy);
+        assert_eq!(relation((0, max - 2), (max, max)), Relation::Before);
+        assert_eq!(relation((0, max), (5, 6)), Relation::Contains);
+    }
+
+    #[test]
+    fn test_count_relations() {
+        let input = crate::read_file("examples", 4);
+        let counts: Vec<String> = count_relations(&input)
+            .into_iter()
+            .filter(|&(_, count)| count > 0)
+            .map(|(relation, count)| format!("{}: {}", relation, count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec!["before: 1", "meets: 1", "overlaps: 2", "finishes: 1", "contains: 1"]
+        );
+    }
+
+    #[test]
+    fn test_coverage() {
+        let input = crate::read_file("examples", 4);
+        let assignments = parse_all_assignments(&input);
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(
+            coverage.sections_by_elves(),
+            vec![(1, 1), (4, 2), (5, 1), (6, 1), (7, 2), (8, 1)]
+        );
+        assert_eq!(
+            coverage.max_coverage(),
+            Some(Segment { start: 6, end: 6, elves: 8 })
+        );
+        assert_eq!(coverage.gaps(), vec![]);
+        // only 7-9 has a section of its own, 9
+        assert_eq!(
+            coverage.redundant(&assignments),
+            vec![0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11]
+        );
+
+        let assignments = [(1, 3), (2, 2), (6, 9), (9, 9), (8, 10), (12, 12)];
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(
+            coverage.sections_by_elves(),
+            vec![(0, 3), (1, 6), (2, 2), (3, 1)]
+        );
+        assert_eq!(coverage.gaps(), vec![(4, 5), (11, 11)]);
+        assert_eq!(
+            coverage.max_coverage(),
+            Some(Segment { start: 9, end: 9, elves: 3 })
+        );
+        assert_eq!(coverage.redundant(&assignments), vec![1, 3]);
+
+        let coverage = Coverage::new(&[(u32::MAX - 1, u32::MAX), (0, 0)]);
+        assert_eq!(coverage.gaps(), vec![(1, u32::MAX - 2)]);
+        assert_eq!(Coverage::new(&[]).to_string(), "no assignments");
+    }
+
+    #[test]
+    fn test_coverage_against_brute_force() {
+        let mut rng = Rng::new(4);
+        for size in (1..120).step_by(9) {
+            let assignments = parse_all_assignments(&generators::day04(&mut rng, size));
+            let coverage = Coverage::new(&assignments);
+            let elves = |section: u32| {
+                assignments
+                    .iter()
+                    .filter(|&&(start, end)| (start..=end).contains(&section))
+                    .count()
+            };
+            for segment in coverage.segments() {
+                for section in segment.start..=segment.end {
+                    assert_eq!(elves(section), segment.elves);
+                }
+            }
+            let redundant: Vec<usize> = (0..assignments.len())
+                .filter(|&index| {
+                    let (start, end) = assignments[index];
+                    (start..=end).all(|section| elves(section) >= 2)
+                })
+                .collect();
+            assert_eq!(coverage.redundant(&assignments), redundant);
+            let gaps: Vec<(u32, u32)> = coverage
+                .segments()
+                .iter()
+                .filter(|segment| elves(segment.start) == 0)
+                .map(|segment| (segment.start, segment.end))
+                .collect();
+            assert_eq!(coverage.gaps(), gaps);
+        }
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(4, part_one, part_two);
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::fmt::Formatter;
+
+use crate::helpers::parse::{ints, ints_n};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ship {
+    containers: HashMap<usize, Vec<String>>,
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Ship { containers: HashMap::new() }
+    }
+
+    pub fn add_stack(&mut self, queue_id: usize) {
+        self.containers.entry(queue_id).or_default();
+    }
+
+    pub fn add_container(&mut self, queue_id: usize, item: String) {
+        let lifo = self.containers.entry(queue_id).or_default();
+        lifo.insert(0, item);
+    }
+
+    /// Moves the top crate of a stack onto another one, as the CrateMover 9000 does. Nothing
+    /// moves unless both stacks exist and the origin isn't empty.
+    pub fn move_crate(
+        &mut self,
+        origin_index: &usize,
+        destination_index: &usize,
+    ) -> Result<(), MoveError> {
+        self.move_multiple_crates(origin_index, destination_index, 1)
+    }
+
+    /// Moves the top `amount` crates of a stack onto another one at once, keeping their order
+    /// like the CrateMover 9001. Nothing moves unless both stacks exist and the origin has
+    /// enough crates.
+    pub fn move_multiple_crates(
+        &mut self,
+        origin_index: &usize,
+        destination_index: &usize,
+        amount: usize,
+    ) -> Result<(), MoveError> {
+        self.check_move(origin_index, destination_index, amount)?;
+        let origin_queue = self.containers.get_mut(origin_index).unwrap();
+        let popped_elems = origin_queue.split_off(origin_queue.len() - amount);
+        self.containers.get_mut(destination_index).unwrap().extend(popped_elems);
+        Ok(())
+    }
+
+    fn check_move(
+        &self,
+        origin_index: &usize,
+        destination_index: &usize,
+        amount: usize,
+    ) -> Result<(), MoveError> {
+        let available = match self.containers.get(origin_index) {
+            Some(queue) => queue.len(),
+            None => return Err(MoveError::UnknownStack(*origin_index)),
+        };
+        if !self.containers.contains_key(destination_index) {
+            return Err(MoveError::UnknownStack(*destination_index));
+        }
+        if available < amount {
+            return Err(MoveError::NotEnoughCrates { stack: *origin_index, amount, available });
+        }
+        Ok(())
+    }
+
+    pub fn get_top_crates(&self) -> String {
+        let mut output = String::from("");
+        let vecs = self.containers.len();
+        for i in 1..(vecs + 1) {
+            let queue = match self.containers.get(&i) {
+                Some(queue) => queue,
+                None => {
+                    println!("empty queue");
+                    continue;
+                }
+            };
+            output += queue.last().unwrap_or(&String::from(" "));
+        }
+        output
+    }
+}
+
+/// Why a move can't be done.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveError {
+    UnknownStack(usize),
+    NotEnoughCrates { stack: usize, amount: usize, available: usize },
+    InvalidInstruction(String),
+}
+
+impl std::fmt::Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::UnknownStack(stack) => write!(f, "unknown stack {}", stack),
+            MoveError::NotEnoughCrates { stack, amount, available } => write!(
+                f,
+                "can't move {} crates from stack {}, it has {}",
+                amount, stack, available
+            ),
+            MoveError::InvalidInstruction(line) => write!(f, "invalid move instruction {:?}", line),
+        }
+    }
+}
+
+/// A move that can't be done, and its line in the input, starting at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CraneError {
+    pub line: usize,
+    pub error: MoveError,
+}
+
+impl std::fmt::Display for CraneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for CraneError {}
+
+/// What to do with moves that can't be done.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Stop at the first one.
+    Strict,
+    /// Skip them, and report them once every move is done.
+    Lenient,
+}
+
+/// The ship once every move is done, and the moves that were skipped.
+pub struct Operation {
+    pub ship: Ship,
+    pub skipped: Vec<CraneError>,
+}
+
+/// How wide the stacks of a drawing are: 3, like a crate, unless the largest id is wider.
+fn stack_width(stacks: usize) -> usize {
+    stacks.to_string().len().max(3)
+}
+
+/// Draws the stacks like the puzzle does, every line as wide as the base line, so that the
+/// drawing reads back with `parse_drawing`. Stacks are numbered from 1, and crates and ids
+/// are centred in stacks as wide as the largest id, which keeps the puzzle's layout up to
+/// 999 stacks.
+impl std::fmt::Display for Ship {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let stacks = self.containers.keys().max().copied().unwrap_or(0);
+        let width = stack_width(stacks);
+        let height = self.containers.values().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let cells: Vec<String> = (1..=stacks)
+                .map(|queue_id| self.containers.get(&queue_id).and_then(|lifo| lifo.get(level)))
+                .map(|item| match item {
+                    Some(item) => format!("{:^width$}", format!("[{}]", item)),
+                    None => " ".repeat(width),
+                })
+                .collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        let ids: Vec<String> = (1..=stacks)
+            .map(|queue_id| format!("{:^width$}", queue_id))
+            .collect();
+        write!(f, "{}", ids.join(" "))
+    }
+}
+
+// crates in stacks `width` wide, separated by a space: 3 in the puzzle, see `stack_width`
+pub fn parse_crates(ship: &mut Ship, line: &str, width: usize) {
+    for (index, char) in line.chars().enumerate() {
+        if !char.is_alphabetic() { continue; }
+        let queue_id = index / (width + 1) + 1;
+        ship.add_container(queue_id, char.to_string());
+    }
+}
+
+// the numbers under the stacks, which also declare the stacks that start empty; one too big
+// for a usize couldn't be the id of a stack anyway
+pub fn parse_stack_ids(ship: &mut Ship, line: &str) {
+    for queue_id in ints::<usize>(line).flatten() {
+        ship.add_stack(queue_id);
+    }
+}
+
+pub fn parse_move_instructions(
+    ship: &mut Ship,
+    line: &str,
+    multiple_enabled: bool,
+) -> Result<(), MoveError> {
+    let [amount, from, to] = ints_n::<usize, 3>(line)
+        .ok_or_else(|| MoveError::InvalidInstruction(line.to_string()))?;
+    if multiple_enabled {
+        ship.move_multiple_crates(&from, &to, amount)
+    } else {
+        // checked first so that the crates move all or not at all
+        ship.check_move(&from, &to, amount)?;
+        for _ in 0..amount {
+            ship.move_crate(&from, &to)?;
+        }
+        Ok(())
+    }
+}
+
+/// The stacks of a drawing like the one at the top of the input, or one drawn by `Ship`.
+pub fn parse_drawing(drawing: &str) -> Ship {
+    let is_ids = |line: &str| !line.contains('[') && line.trim_start().starts_with('1');
+    let stacks = drawing
+        .lines()
+        .filter(|line| is_ids(line))
+        .flat_map(|line| ints::<usize>(line).flatten())
+        .max()
+        .unwrap_or(0);
+    let mut ship = Ship::new();
+    for line in drawing.lines() {
+        match line {
+            x if x.contains("[") => parse_crates(&mut ship, line, stack_width(stacks)),
+            x if x.trim_start().starts_with('1') => parse_stack_ids(&mut ship, line),
+            _ => {}
+        }
+    }
+    ship
+}
+
+/// Reads the stacks and runs every move of the input, with the CrateMover 9001 when
+/// `multiple_enabled`.
+pub fn operate_crane(
+    input: &str,
+    multiple_enabled: bool,
+    mode: Mode,
+) -> Result<Operation, CraneError> {
+    operate_crane_with(input, multiple_enabled, mode, |_, _| {})
+}
+
+/// Like `operate_crane`, calling `on_move` with the line and the ship after every move done.
+pub fn operate_crane_with(
+    input: &str,
+    multiple_enabled: bool,
+    mode: Mode,
+    mut on_move: impl FnMut(&str, &Ship),
+) -> Result<Operation, CraneError> {
+    // the drawing comes before the first move, its ids giving the width of the stacks
+    let lines: Vec<&str> = input.lines().collect();
+    let first_move = lines
+        .iter()
+        .position(|line| line.contains("move"))
+        .unwrap_or(lines.len());
+    let mut ship = parse_drawing(&lines[..first_move].join("\n"));
+    let mut skipped = Vec::new();
+
+    for (index, line) in lines.iter().enumerate().skip(first_move) {
+        if !line.contains("move") {
+            continue;
+        }
+        match parse_move_instructions(&mut ship, line, multiple_enabled) {
+            Ok(()) => on_move(line, &ship),
+            Err(error) => {
+                let error = CraneError { line: index + 1, error };
+                match mode {
+                    Mode::Strict => return Err(error),
+                    Mode::Lenient => skipped.push(error),
+                }
+            }
+        }
+    }
+    Ok(Operation { ship, skipped })
+}
+
+/// `None` if a move can't be done, see `operate_crane` for the error.
+fn top_crates(input: &str, multiple_enabled: bool) -> Option<String> {
+    let operation = operate_crane(input, multiple_enabled, Mode::Strict).ok()?;
+    Some(operation.ship.get_top_crates())
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    top_crates(input, false)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    top_crates(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 5);
+        assert_eq!(part_one(&input), Some(String::from("CMZ")));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 5);
+        assert_eq!(part_two(&input), Some(String::from("MCD")));
+    }
+
+    #[test]
+    fn test_invalid_moves() {
+        let input = crate::read_file("examples", 5);
+        let input = input.replace("move 3 from 1 to 3", "move 4 from 1 to 3\nmove 1 from 4 to 1");
+        let error = operate_crane(&input, false, Mode::Strict).err().unwrap();
+        assert_eq!(
+            error,
+            CraneError {
+                line: 7,
+                error: MoveError::NotEnoughCrates { stack: 1, amount: 4, available: 3 }
+            }
+        );
+        assert_eq!(error.to_string(), "line 7: can't move 4 crates from stack 1, it has 3");
+
+        let operation = operate_crane(&input, true, Mode::Lenient).unwrap();
+        let skipped: Vec<String> = operation.skipped.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            skipped,
+            vec![
+                "line 7: can't move 4 crates from stack 1, it has 3",
+                "line 8: unknown stack 4",
+            ]
+        );
+        // the skipped moves left the stacks untouched
+        assert_eq!(operation.ship.get_top_crates(), "MCP");
+
+        assert_eq!((part_one(&input), part_two(&input)), (None, None));
+
+        let error = operate_crane("move one from 1 to 2", true, Mode::Strict).err().unwrap();
+        assert_eq!(error.to_string(), "line 1: invalid move instruction \"move one from 1 to 2\"");
+    }
+
+    #[test]
+    fn test_drawing() {
+        let input = crate::read_file("examples", 5);
+        let (drawing, _) = input.split_once("\n\n").unwrap();
+        let ship = parse_drawing(drawing);
+        let rendered = ship.to_string();
+        let trimmed: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+        assert_eq!(trimmed.join("\n"), drawing.trim_end());
+        assert_eq!(rendered.lines().next(), Some("    [D]    "));
+        assert_eq!(parse_drawing(&rendered), ship);
+
+        // empty stacks are still drawn
+        let ship = parse_drawing("[A]     [B]\n 1   2   3 ");
+        assert_eq!(ship.to_string(), "[A]     [B]\n 1   2   3 ");
+        assert_eq!(parse_drawing(&ship.to_string()), ship);
+        assert_eq!(Ship::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_drawing_of_many_stacks() {
+        let mut ship = Ship::new();
+        for queue_id in 1..=12 {
+            ship.add_stack(queue_id);
+        }
+        ship.add_container(1, "A".to_string());
+        ship.add_container(10, "B".to_string());
+        ship.add_container(10, "C".to_string());
+        ship.add_container(12, "D".to_string());
+        let rendered = ship.to_string();
+        assert_eq!(
+            rendered,
+            concat!(
+                "                                    [B]        \n",
+                "[A]                                 [C]     [D]\n",
+                " 1   2   3   4   5   6   7   8   9  10  11  12 ",
+            )
+        );
+        assert_eq!(parse_drawing(&rendered), ship);
+
+        // wider ids make wider stacks
+        let mut ship = Ship::new();
+        for queue_id in 1..=1000 {
+            ship.add_stack(queue_id);
+        }
+        ship.add_container(1, "A".to_string());
+        ship.add_container(999, "B".to_string());
+        ship.add_container(1000, "C".to_string());
+        let rendered = ship.to_string();
+        assert!(rendered.starts_with("[A] "));
+        assert!(rendered.ends_with(" 999  1000"));
+        assert_eq!(parse_drawing(&rendered), ship);
+
+        // and the moves run on them
+        let input = format!("{}\n\nmove 1 from 999 to 1000\nmove 2 from 1000 to 2\n", rendered);
+        let operation = operate_crane(&input, true, Mode::Strict).unwrap();
+        let mut moved = Ship::new();
+        for queue_id in 1..=1000 {
+            moved.add_stack(queue_id);
+        }
+        moved.add_container(1, "A".to_string());
+        moved.add_container(2, "B".to_string());
+        moved.add_container(2, "C".to_string());
+        assert_eq!(operation.ship, moved);
+        let error = operate_crane(&format!("{}move 1 from 1001 to 1", input), true, Mode::Strict);
+        assert_eq!(error.err().map(|error| error.line), Some(6));
+    }
+
+    #[test]
+    fn test_drawing_after_every_move() {
+        let input = crate::read_file("examples", 5);
+        let mut states = Vec::new();
+        operate_crane_with(&input, true, Mode::Strict, |line, ship| {
+            states.push(format!("{}\n{}", line, ship));
+        })
+        .unwrap();
+        crate::assert_snapshot!(5, "moves", states.join("\n\n"));
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(5, part_one, part_two);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::helpers::grid::Grid;
+#[cfg(test)]
+use crate::helpers::grid::ORTHOGONAL;
+
+pub fn init_height_matrix(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10).unwrap())
+}
+
+// Brute force: walk every ray from every tree. Kept as a reference for the differential tests.
+#[cfg(test)]
+fn solve_problem_1_naive(heights: &Grid<u32>) -> usize {
+    heights
+        .iter()
+        .filter(|&((x, y), &current_height)| {
+            // a tree on the edge has an empty ray and is always visible
+            ORTHOGONAL.iter().any(|&direction| {
+                heights
+                    .ray(x, y, direction)
+                    .all(|position| heights[position] < current_height)
+            })
+        })
+        .count()
+}
+
+#[cfg(test)]
+fn solve_problem_2_naive(heights: &Grid<u32>) -> usize {
+    let mut max = 0;
+    for ((x, y), &current_height) in heights.iter() {
+        let mut max_distances = [0; 4];
+        for (dir_index, &direction) in ORTHOGONAL.iter().enumerate() {
+            for (distance, position) in heights.ray(x, y, direction).enumerate() {
+                max_distances[dir_index] = distance + 1;
+                if heights[position] >= current_height {
+                    break;
+                }
+            }
+        }
+        let distance = max_distances.iter().product();
+        if distance > max {
+            max = distance;
+        }
+    }
+    max
+}
+
+/// Sweeps every line once from each end, keeping the tallest tree seen so far:
+/// a tree is visible if it is taller than everything before it in one of the sweeps.
+pub fn solve_problem_1(heights: &Grid<u32>) -> usize {
+    let (width, height) = (heights.width(), heights.height());
+    let mut visible = Grid::filled(width, height, false);
+    let mut sweep = |positions: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut tallest = None;
+        for position in positions {
+            if tallest < Some(heights[position]) {
+                visible[position] = true;
+                tallest = Some(heights[position]);
+            }
+        }
+    };
+    for y in 0..height {
+        sweep(&mut (0..width).map(|x| (x, y)));
+        sweep(&mut (0..width).rev().map(|x| (x, y)));
+    }
+    for x in 0..width {
+        sweep(&mut (0..height).map(|y| (x, y)));
+        sweep(&mut (0..height).rev().map(|y| (x, y)));
+    }
+    visible.iter().filter(|(_, &v)| v).count()
+}
+
+/// Sweeps every line once from each end, remembering for each height the last tree
+/// at least that tall: the viewing distance is the distance back to it.
+pub fn solve_problem_2(heights: &Grid<u32>) -> usize {
+    let (width, height) = (heights.width(), heights.height());
+    let mut scores = Grid::filled(width, height, 1);
+    let mut sweep = |positions: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut blocker = [0; 10];
+        for (distance, position) in positions.enumerate() {
+            let tree = heights[position] as usize;
+            scores[position] *= distance - blocker[tree];
+            blocker[..=tree].fill(distance);
+        }
+    };
+    for y in 0..height {
+        sweep(&mut (0..width).map(|x| (x, y)));
+        sweep(&mut (0..width).rev().map(|x| (x, y)));
+    }
+    for x in 0..width {
+        sweep(&mut (0..height).map(|y| (x, y)));
+        sweep(&mut (0..height).rev().map(|y| (x, y)));
+    }
+    scores.iter().map(|(_, &score)| score).max().unwrap_or(0)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let heights = init_height_matrix(input);
+    Some(solve_problem_1(&heights))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let heights = init_height_matrix(input);
+    Some(solve_problem_2(&heights))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use crate::helpers::differential::Differential;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+
+    #[test]
+    fn test_differential() {
+        let report = Differential::new(generators::day08)
+            .implementation("brute force", |input| Some(solve_problem_1_naive(&init_height_matrix(input))))
+            .implementation("sweep", part_one)
+            .assert_agree();
+        println!("{}", report);
+        let report = Differential::new(generators::day08)
+            .implementation("brute force", |input| Some(solve_problem_2_naive(&init_height_matrix(input))))
+            .implementation("sweep", part_two)
+            .assert_agree();
+        println!("{}", report);
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(8, part_one, part_two);
+    }
+}
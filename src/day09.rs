@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use crate::helpers::{BoundingBox, Direction, Direction8, Point2};
+use crate::visualize::{Frame, Visualize};
+
+pub type Point = Point2<i32>;
+
+pub struct Grid {
+    rope: Vec<Point>,
+    visited: HashSet<Point>,
+}
+
+impl Grid {
+    pub fn new() -> Grid {
+        let mut grid = Grid {
+            rope: Vec::new(),
+            visited: HashSet::new(),
+        };
+        grid.visited.insert(Point::ORIGIN);
+        grid
+    }
+
+    pub fn move_knots(&mut self, i: usize, move_dir: Direction8) {
+        let new_knot_pos = self.rope[i] + move_dir.offset();
+        self.rope[i] = new_knot_pos;
+
+        if i == self.rope.len() - 1 { // stop condition : last knot moved
+            self.visited.insert(new_knot_pos);
+            return;
+        }
+        // knots touch as long as they are at most one king move apart
+        if new_knot_pos.chebyshev_distance(&self.rope[i + 1]) > 1 {
+            // Either on same row or same column, move in that direction
+            // Or move diagonally next to the head (which is actually two moves on x and y)
+            let follow = Direction8::from_offset(new_knot_pos - self.rope[i + 1]).unwrap();
+            self.move_knots(i + 1, follow);
+        }
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Grid {
+        Grid::new()
+    }
+}
+
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut grid = Grid::new();
+    grid.rope.push(Point::ORIGIN);
+    grid.rope.push(Point::ORIGIN);
+    run_algo(input, grid)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut grid = Grid::new();
+    for _ in 0..10 {
+        grid.rope.push(Point::ORIGIN);
+    }
+    run_algo(input, grid)
+}
+
+pub fn parse_moves(input: &str) -> Vec<(Direction, u32)> {
+    input
+        .lines()
+        .map(|line| {
+            let instructions = line.split_whitespace().collect::<Vec<&str>>();
+            let move_dir = match instructions[0] {
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                "R" => Direction::Right,
+                _ => panic!("Invalid direction"),
+            };
+            (move_dir, instructions[1].parse().unwrap_or(0))
+        })
+        .collect()
+}
+
+fn run_algo(input: &str, mut grid: Grid) -> Option<u32> {
+    for (move_dir, steps) in parse_moves(input) {
+        for _ in 0..steps {
+            grid.move_knots(0, move_dir.into());
+        }
+    }
+    Some(grid.visited.len() as u32)
+}
+
+/// The rope moving one step at a time: `H` for the head, `1` to `9` for the knots that
+/// follow it and `#` where the tail has been.
+pub struct RopeAnimation {
+    moves: Vec<(Direction, u32)>,
+    knots: usize,
+}
+
+impl RopeAnimation {
+    pub fn new(input: &str, knots: usize) -> RopeAnimation {
+        RopeAnimation {
+            moves: parse_moves(input),
+            knots,
+        }
+    }
+}
+
+impl Visualize for RopeAnimation {
+    fn frames(self) -> Box<dyn Iterator<Item = Frame>> {
+        let steps: Vec<Direction> = self
+            .moves
+            .iter()
+            .flat_map(|&(move_dir, steps)| (0..steps).map(move |_| move_dir))
+            .collect();
+        // every knot stays within the area covered by the head
+        let mut head = Point::ORIGIN;
+        let mut bounds = BoundingBox { min: head, max: head };
+        for &step in &steps {
+            head += step.offset();
+            bounds.include(&head);
+        }
+
+        let mut grid = Grid::new();
+        grid.rope = vec![Point::ORIGIN; self.knots];
+        let draw = move |grid: &Grid, step: usize| {
+            let mut cells = crate::helpers::Grid::filled(
+                bounds.width() as usize,
+                bounds.height() as usize,
+                '.',
+            );
+            let cell = |point: &Point| {
+                ((point.x - bounds.min.x) as usize, (point.y - bounds.min.y) as usize)
+            };
+            for point in &grid.visited {
+                cells[cell(point)] = '#';
+            }
+            for (i, knot) in grid.rope.iter().enumerate().rev() {
+                cells[cell(knot)] = match i {
+                    0 => 'H',
+                    _ => char::from_digit(i as u32 % 10, 10).unwrap(),
+                };
+            }
+            let caption = format!(
+                "step {}, {} positions visited by the tail",
+                step,
+                grid.visited.len()
+            );
+            Frame::new(caption, cells)
+        };
+        let first = draw(&grid, 0);
+        let moves = steps.into_iter().enumerate().map(move |(i, step)| {
+            grid.move_knots(0, step.into());
+            draw(&grid, i + 1)
+        });
+        Box::new(std::iter::once(first).chain(moves))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 9);
+        assert_eq!(part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 9);
+        assert_eq!(part_two(&input), Some(1));
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = crate::read_file("examples", 9);
+        let frames: Vec<Frame> = RopeAnimation::new(&input, 2).frames().collect();
+        // one frame before the first step, then one per step
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[24].caption, "step 24, 13 positions visited by the tail");
+        crate::assert_snapshot!(9, "rope", frames[24].to_string());
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(9, part_one, part_two);
+    }
+}
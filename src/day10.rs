@@ -0,0 +1,187 @@
+use crate::helpers::Grid;
+use crate::visualize::{Frame, Visualize};
+use crate::Answer;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Command {
+    Noop,
+    Addx(isize),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Cpu {
+    cycle: isize,
+    value: isize,
+    current_command: Option<(Command, u8)>,
+}
+
+impl Cpu {
+    pub fn new() -> Cpu {
+        Cpu {
+            cycle: 1,
+            value: 1,
+            current_command: None,
+        }
+    }
+
+    pub fn execute_command(&mut self, command: Command) {
+        match command {
+            Command::Noop => self.current_command = Some((Command::Noop, 1)),
+            Command::Addx(x) => {
+                self.current_command = Some((Command::Addx(x), 2));
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if let Some((command, remaining_cycles)) = &self.current_command {
+            self.cycle += 1;
+            match remaining_cycles - 1 {
+                0 => {
+                    match command {
+                        Command::Noop => {}
+                        Command::Addx(x) => self.value += x,
+                    }
+                    self.current_command = None;
+                }
+                _ => {
+                    self.current_command = Some((*command, remaining_cycles - 1));
+                }
+            }
+        }
+    }
+
+    pub fn get_signal_strength(&self) -> isize {
+        self.value * self.cycle
+    }
+
+    pub fn render_pixels(&self) -> &str {
+        match (((self.cycle-1) % 40)-self.value).abs() {
+            0 | 1 => "#",
+            _ => "."
+        }
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Cpu {
+        Cpu::new()
+    }
+}
+
+pub fn parse_command(line: &str) -> Command {
+    let instruction = line.split_whitespace().collect::<Vec<&str>>();
+    let (command, argument) = (instruction.first(), instruction.get(1));
+    match command {
+        Some(&"noop") => Command::Noop,
+        Some(&"addx") => Command::Addx(argument.unwrap().parse().unwrap()),
+        _ => panic!("Invalid command"),
+    }
+}
+
+pub fn part_one(input: &str) -> Option<isize> {
+    let mut cpu = Cpu::new();
+    let mut signal_strength_sum = 0;
+    for line in input.lines() {
+        cpu.execute_command(parse_command(line));
+        while cpu.current_command.is_some() {
+            if (cpu.cycle + 20) % 40 == 0 {
+                signal_strength_sum += cpu.get_signal_strength();
+            }
+            cpu.tick();
+        }
+
+    }
+    Some(signal_strength_sum)
+}
+
+pub fn part_two(input: &str) -> Option<Answer> {
+    let mut cpu = Cpu::new();
+    let mut screen = String::new();
+    for line in input.lines() {
+        cpu.execute_command(parse_command(line));
+        while cpu.current_command.is_some() {
+            screen.push_str(cpu.render_pixels());
+            if (cpu.cycle + 40) % 40 == 0 {
+                screen.push('\n');
+            }
+            cpu.tick();
+        }
+    }
+    Some(Answer::rendered(screen.trim_end()))
+}
+
+/// The CRT drawn one pixel per cycle, with the 3 pixels wide sprite below it.
+pub struct CrtAnimation {
+    commands: Vec<Command>,
+}
+
+impl CrtAnimation {
+    pub fn new(input: &str) -> CrtAnimation {
+        CrtAnimation {
+            commands: input.lines().map(parse_command).collect(),
+        }
+    }
+}
+
+impl Visualize for CrtAnimation {
+    fn frames(self) -> Box<dyn Iterator<Item = Frame>> {
+        let mut cpu = Cpu::new();
+        let mut commands = self.commands.into_iter();
+        // 6 rows of screen, a blank row and the sprite row
+        let mut screen = Grid::filled(40, 8, ' ');
+        Box::new(std::iter::from_fn(move || {
+            if cpu.current_command.is_none() {
+                cpu.execute_command(commands.next()?);
+            }
+            let pixel = (cpu.cycle - 1) as usize;
+            if pixel < 240 {
+                screen[(pixel % 40, pixel / 40)] = cpu.render_pixels().chars().next().unwrap();
+            }
+            for x in 0..40 {
+                screen[(x, 7)] = match (x as isize - cpu.value).abs() {
+                    0 | 1 => '~',
+                    _ => ' ',
+                };
+            }
+            let caption = format!("cycle {}, X = {}", cpu.cycle, cpu.value);
+            let frame = Frame::new(caption, screen.clone());
+            cpu.tick();
+            Some(frame)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 10);
+        assert_eq!(part_one(&input), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 10);
+        // the example draws stripes rather than letters
+        let answer = part_two(&input).unwrap();
+        assert_eq!(answer.short(), None);
+        crate::assert_snapshot!(10, "crt", answer.image().unwrap());
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = crate::read_file("examples", 10);
+        let last = CrtAnimation::new(&input).frames().last().unwrap();
+        assert_eq!(last.caption, "cycle 240, X = 17");
+        let screen = part_two(&input).unwrap();
+        assert!(last.to_string().contains(screen.image().unwrap()));
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(10, part_one, part_two);
+    }
+}
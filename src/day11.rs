@@ -0,0 +1,191 @@
+extern crate core;
+
+use std::mem;
+
+use crate::helpers::parse::{ints, ints_n, pair, parse_all, preceded, tag, take_while1, terminated, word, ws};
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Add(String),
+    Subtract(String),
+    Multiply(String),
+    Divide(String),
+    Modulo(String),
+}
+
+impl Operation {
+    pub fn new(op: &str, arg: &str) -> Operation {
+        match op {
+            "+" => Operation::Add(arg.to_string()),
+            "-" => Operation::Subtract(arg.to_string()),
+            "*" => Operation::Multiply(arg.to_string()),
+            "/" => Operation::Divide(arg.to_string()),
+            "%" => Operation::Modulo(arg.to_string()),
+            _ => panic!("Unknown operation {}", op),
+        }
+    }
+
+    pub fn get_arg(&self) -> Option<u64> {
+        match self {
+            Operation::Add(arg) => arg.parse::<u64>().ok(),
+            Operation::Subtract(arg) => arg.parse::<u64>().ok(),
+            Operation::Multiply(arg) => arg.parse::<u64>().ok(),
+            Operation::Divide(arg) => arg.parse::<u64>().ok(),
+            Operation::Modulo(arg) => arg.parse::<u64>().ok(),
+        }
+    }
+
+    pub fn execute(&self, value: u64) -> u64 {
+        match &self {
+            Operation::Add(arg) => value + arg.parse::<u64>().unwrap_or(value),
+            Operation::Subtract(arg) => value - arg.parse::<u64>().unwrap_or(value),
+            Operation::Multiply(arg) => value * arg.parse::<u64>().unwrap_or(value),
+            Operation::Divide(arg) => value / arg.parse::<u64>().unwrap_or(value),
+            Operation::Modulo(arg) => value % arg.parse::<u64>().unwrap_or(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Inspection {
+    operation: Operation,
+    destination_true: u64,
+    destination_false: u64,
+}
+
+impl Inspection {
+    pub fn new(raw_arg: &str, raw_dest_true: &str, raw_dest_false: &str) -> Inspection {
+        let [arg] = ints_n::<u64, 1>(raw_arg).expect("invalid test");
+        let [dest_true] = ints_n::<u64, 1>(raw_dest_true).expect("invalid true branch");
+        let [dest_false] = ints_n::<u64, 1>(raw_dest_false).expect("invalid false branch");
+        Inspection {
+            operation: Operation::Modulo(arg.to_string()),
+            destination_true: dest_true,
+            destination_false: dest_false,
+        }
+    }
+
+    pub fn execute(&self, value: u64) -> u64 {
+        if self.operation.execute(value) == 0 {
+            self.destination_true
+        } else {
+            self.destination_false
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    inspection: Inspection,
+    count: u64,
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey {
+            items: vec![],
+            operation: Operation::Add("1".to_string()),
+            inspection: Inspection::new("by 1", "monkey 1", "monkey 1"),
+            count: 0,
+        }
+    }
+
+    pub fn new_from_text(raw_monkey: &[&str]) -> Monkey {
+        let items = ints::<u64>(raw_monkey[1]).collect::<Result<Vec<u64>, _>>().unwrap();
+        let operation_parser = preceded(
+            tag("Operation: new = old "),
+            pair(terminated(take_while1("an operator", |c| "+-*/%".contains(c)), ws), word()),
+        );
+        let (op, arg) = parse_all(operation_parser, raw_monkey[2]).unwrap();
+        let operation = Operation::new(op, arg);
+        let inspection = Inspection::new(raw_monkey[3], raw_monkey[4], raw_monkey[5]);
+        Monkey {
+            items,
+            operation,
+            inspection,
+            count: 0,
+        }
+    }
+
+    pub fn inspect_elements(&mut self, monkeys: &mut [Monkey], worry_management: Operation) {
+        for item in self.items.drain(..) {
+            let raised_worriness = &self.operation.execute(item);
+            let new_worry_level = worry_management.execute(*raised_worriness);
+            let target_throw = &self.inspection.execute(new_worry_level);
+            monkeys.get_mut(*target_throw as usize).unwrap().items.push(new_worry_level);
+            self.count += 1;
+        }
+        self.items.clear();
+    }
+}
+
+pub fn parse_initial_state(raw_monkeys: Vec<&[&str]>) -> Vec<Monkey> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    for raw_monkey in raw_monkeys {
+        monkeys.push(Monkey::new_from_text(raw_monkey));
+    }
+    monkeys
+}
+
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let lines: Vec<&str> = input.lines().collect();
+    let raw_monkeys = lines.chunks(7).collect::<Vec<&[&str]>>();
+    let mut monkeys = parse_initial_state(raw_monkeys);
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let mut monkey = Monkey::new();
+            mem::swap(&mut monkeys[i], &mut monkey);
+            monkey.inspect_elements(&mut monkeys, Operation::Divide(3.to_string()));
+            mem::swap(&mut monkeys[i], &mut monkey);
+        }
+    }
+    monkeys.sort_by_key(|a| a.count);
+    let top_2_monkeys = monkeys.iter().rev().take(2).collect::<Vec<&Monkey>>();
+    Some(top_2_monkeys[0].count * top_2_monkeys[1].count)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let lines: Vec<&str> = input.lines().collect();
+    let raw_monkeys = lines.chunks(7).collect::<Vec<&[&str]>>();
+    let mut monkeys = parse_initial_state(raw_monkeys);
+    let common_divisor = monkeys.iter().fold(
+        1,
+        |acc, monkey| acc * monkey.inspection.operation.get_arg().unwrap()
+    );
+    for _ in 0..10000 {
+        for i in 0..monkeys.len() {
+            let mut monkey = Monkey::new();
+            mem::swap(&mut monkeys[i], &mut monkey);
+            monkey.inspect_elements(&mut monkeys, Operation::Modulo(common_divisor.to_string()));
+            mem::swap(&mut monkeys[i], &mut monkey);
+        }
+    }
+    monkeys.sort_by_key(|a| a.count);
+    let top_2_monkeys = monkeys.iter().rev().take(2).collect::<Vec<&Monkey>>();
+    Some(top_2_monkeys[0].count * top_2_monkeys[1].count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 11);
+        assert_eq!(part_one(&input), Some(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 11);
+        assert_eq!(part_two(&input), Some(2713310158));
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(11, part_one, part_two);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::helpers::search::{astar, bfs, SearchResult};
+use crate::helpers::{Grid, Point2};
+use crate::visualize::{Frame, Visualize};
+
+#[derive(Clone, Debug)]
+pub struct HeightMap {
+    grid: Grid<char>,
+}
+
+pub type Point = Point2<usize>;
+
+impl HeightMap {
+    pub fn get_point(&self, point: &Point) -> char {
+        self.grid[*point]
+    }
+
+    pub fn get_neighbors(&self, point: &Point) -> Vec<Point> {
+        self.grid
+            .neighbours4(point.x, point.y)
+            .map(|(x, y)| Point::new(x, y))
+            .collect()
+    }
+
+    pub fn get_first_position(&self, c: char) -> Point {
+        match self.grid.position(|&cell| cell == c) {
+            Some((x, y)) => Point::new(x, y),
+            None => panic!("Could not find position for char {c}"),
+        }
+    }
+
+    /// Draws `path` the way the puzzle does: an arrow on each step pointing to the next one,
+    /// `E` at the end and `.` everywhere else.
+    #[allow(dead_code)] // debugging helper
+    fn render(&self, path: &[Point]) -> String {
+        self.draw_path(Grid::filled(self.grid.width(), self.grid.height(), '.'), path)
+            .to_string()
+    }
+
+    fn draw_path(&self, mut drawing: Grid<char>, path: &[Point]) -> Grid<char> {
+        for step in path.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            drawing[from] = match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+                (std::cmp::Ordering::Greater, _) => '>',
+                (std::cmp::Ordering::Less, _) => '<',
+                (_, std::cmp::Ordering::Greater) => 'v',
+                _ => '^',
+            };
+        }
+        if let Some(&end) = path.last() {
+            drawing[end] = 'E';
+        }
+        drawing
+    }
+}
+
+pub fn parse_heightmap(input: &str) -> HeightMap {
+    HeightMap {
+        grid: Grid::parse(input, |c| c),
+    }
+}
+
+fn char_to_num(mut c: char) -> i32 {
+    if c == 'S' { c = 'a'; }
+    if c == 'E' { c = 'z'; }
+    c as i32 - 48
+}
+
+fn is_reachable(from: char, to: Option<&char>) -> u8 {
+    match to {
+        Some(to) if char_to_num(from) + 1 >= char_to_num(*to) => 1,
+        _ => 0,
+    }
+}
+
+pub fn climb(heightmap: &HeightMap) -> SearchResult<Point, u32> {
+    let source = heightmap.get_first_position('S');
+    let destination = heightmap.get_first_position('E');
+    let climbable = |point: &Point| {
+        heightmap
+            .get_neighbors(point)
+            .into_iter()
+            .filter(|neighbor| is_reachable(heightmap.get_point(point), Some(&heightmap.get_point(neighbor))) == 1)
+            .map(|neighbor| (neighbor, 1))
+            .collect::<Vec<_>>()
+    };
+    // every step costs 1, so the manhattan distance never overestimates the remaining path
+    astar(
+        [source],
+        climbable,
+        |point| point.manhattan_distance(&destination) as u32,
+        |point| *point == destination,
+    )
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let heightmap = parse_heightmap(input);
+    climb(&heightmap).target_distance()
+}
+
+pub fn part_two(input: &str) -> Option<i32> {
+    let heightmap = parse_heightmap(input);
+    let source = heightmap.get_first_position('E');
+    // walk the trail backwards from the summit until the first lowest point
+    let descendable = |point: &Point| {
+        heightmap
+            .get_neighbors(point)
+            .into_iter()
+            .filter(|neighbor| is_reachable(heightmap.get_point(neighbor), Some(&heightmap.get_point(point))) == 1)
+            .collect::<Vec<_>>()
+    };
+    let search = bfs([source], descendable, |point| heightmap.get_point(point) == 'a');
+    search.target_distance().map(|distance| distance as i32)
+}
+
+/// The search spreading from `S` one distance at a time, explored squares in upper case,
+/// then the shortest path drawn over the map.
+impl Visualize for HeightMap {
+    fn frames(self) -> Box<dyn Iterator<Item = Frame>> {
+        let search = climb(&self);
+        let mut layers: Vec<Vec<Point>> = Vec::new();
+        for (point, distance) in search.distances() {
+            let distance = distance as usize;
+            if layers.len() <= distance {
+                layers.resize(distance + 1, Vec::new());
+            }
+            layers[distance].push(*point);
+        }
+
+        let mut map = self.grid.clone();
+        let mut frames = Vec::with_capacity(layers.len() + 1);
+        for (distance, layer) in layers.iter().enumerate() {
+            for point in layer {
+                map[*point] = map[*point].to_ascii_uppercase();
+            }
+            frames.push(Frame::new(format!("distance {}", distance), map.clone()));
+        }
+        if let Some(path) = search.target_path() {
+            let caption = format!("shortest path: {} steps", path.len() - 1);
+            frames.push(Frame::new(caption, self.draw_path(self.grid.clone(), &path)));
+        }
+        Box::new(frames.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_render_path() {
+        let input = crate::read_file("examples", 12);
+        let heightmap = parse_heightmap(&input);
+        let path = climb(&heightmap).target_path().unwrap();
+        crate::assert_snapshot!(12, "path", heightmap.render(&path));
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = crate::read_file("examples", 12);
+        let frames: Vec<Frame> = parse_heightmap(&input).frames().collect();
+        assert_eq!(frames[0].caption, "distance 0");
+        assert_eq!(frames.last().unwrap().caption, "shortest path: 31 steps");
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(12, part_one, part_two);
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use crate::helpers::{BoundingBox, Direction8, Grid, Point2};
+use crate::visualize::{Frame, Visualize};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Cell {
+    pub fn symbol(&self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+pub type Point = Point2<i64>;
+
+/// Where a unit of sand tries to go next, in order of preference.
+const FALLS: [Direction8; 3] = [Direction8::Down, Direction8::DownLeft, Direction8::DownRight];
+
+const ORIGIN: Point = Point::new(500, 0);
+
+/// The slice of the cave that sand can reach: everything the pile could cover when it
+/// rests on the floor, 2 rows below the lowest rock.
+pub struct Cave {
+    grid: Grid<Cell>,
+    x_offset: i64,
+    bottom: i64,
+}
+
+impl Cave {
+    pub fn new(rocks: &[Point]) -> Cave {
+        let mut bounds = BoundingBox::from_points(rocks).unwrap_or(BoundingBox {
+            min: ORIGIN,
+            max: ORIGIN,
+        });
+        let bottom = bounds.max.y;
+        let floor = bottom + 2;
+        bounds.include(&Point::new(ORIGIN.x - floor, 0));
+        bounds.include(&Point::new(ORIGIN.x + floor, floor));
+        let mut cave = Cave {
+            grid: Grid::filled(bounds.width() as usize, bounds.height() as usize, Cell::Air),
+            x_offset: bounds.min.x,
+            bottom,
+        };
+        for rock in rocks {
+            cave.set(rock, Cell::Rock);
+        }
+        cave
+    }
+
+    pub fn get(&self, point: &Point) -> Cell {
+        let x = point.x - self.x_offset;
+        if x < 0 || point.y < 0 {
+            return Cell::Air;
+        }
+        self.grid.get(x as usize, point.y as usize).copied().unwrap_or(Cell::Air)
+    }
+
+    pub fn set(&mut self, point: &Point, cell: Cell) {
+        self.grid[((point.x - self.x_offset) as usize, point.y as usize)] = cell;
+    }
+
+    pub fn is_blocked(&self, point: &Point) -> bool {
+        self.get(point) != Cell::Air
+    }
+
+    pub fn add_floor(&mut self) {
+        let floor = self.bottom + 2;
+        for x in 0..self.grid.width() {
+            self.grid[(x, floor as usize)] = Cell::Rock;
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn get_next_position(sand: Point, cave: &Cave) -> Point {
+    FALLS
+        .iter()
+        .map(|fall| sand + fall.offset())
+        .find(|next_position| !cave.is_blocked(next_position))
+        .unwrap_or(sand)
+}
+
+fn create_line(source: &Point, dest: &Point) -> Vec<Point> {
+    let step = (*dest - *source).signum();
+    let mut line = vec![*source];
+    let mut current_point = *source;
+    while current_point != *dest {
+        current_point += step;
+        line.push(current_point);
+    }
+    line
+}
+
+pub fn parse_input(input: &str) -> Cave {
+    let mut rocks: Vec<Point> = Vec::new();
+    for line in input.lines() {
+        let mut straight_lines = line.split(" -> ").collect::<VecDeque<&str>>();
+        let mut begin = straight_lines.pop_front().unwrap();
+        while !straight_lines.is_empty() {
+            let end = straight_lines.pop_front().unwrap_or(begin);
+            let source = Point::new(
+                begin.split(',').collect::<Vec<&str>>()[0].parse().unwrap(),
+                begin.split(',').collect::<Vec<&str>>()[1].parse().unwrap(),
+            );
+            let dest = Point::new(
+                end.split(',').collect::<Vec<&str>>()[0].parse().unwrap(),
+                end.split(',').collect::<Vec<&str>>()[1].parse().unwrap(),
+            );
+            rocks.append(&mut create_line(&source, &dest));
+            begin = end;
+        }
+    }
+    Cave::new(&rocks)
+}
+
+/// Drops one unit of sand from the origin and returns where it comes to rest,
+/// or `None` if it falls below the lowest rock into the abyss.
+pub fn drop_sand(cave: &Cave) -> Option<Point> {
+    let mut current_point = ORIGIN;
+    loop {
+        let next_position = get_next_position(current_point, cave);
+        if next_position == current_point {
+            return Some(next_position);
+        }
+        if next_position.y > cave.bottom + 2 {
+            return None;
+        }
+        current_point = next_position;
+    }
+}
+
+pub fn pour_sand(cave: &mut Cave) -> u64 {
+    let mut counter: u64 = 0;
+    while let Some(sand) = drop_sand(cave) {
+        cave.set(&sand, Cell::Sand);
+        counter += 1;
+    }
+    counter
+}
+
+pub fn pour_sand_part_2(cave: &mut Cave) -> u64 {
+    let mut counter: u64 = 0;
+    cave.add_floor();
+    // the floor is wide enough to catch every unit, so sand always comes to rest
+    while let Some(sand) = drop_sand(cave) {
+        cave.set(&sand, Cell::Sand);
+        counter += 1;
+        // the source is blocked once a unit rests on it
+        if sand == ORIGIN {
+            break;
+        }
+    }
+    counter
+}
+
+/// The cave after every unit of sand comes to rest, on the floor when `floor` is set.
+pub struct SandAnimation {
+    cave: Cave,
+    floor: bool,
+}
+
+impl SandAnimation {
+    pub fn new(input: &str, floor: bool) -> SandAnimation {
+        SandAnimation {
+            cave: parse_input(input),
+            floor,
+        }
+    }
+}
+
+impl Visualize for SandAnimation {
+    fn frames(self) -> Box<dyn Iterator<Item = Frame>> {
+        let mut cave = self.cave;
+        if self.floor {
+            cave.add_floor();
+        }
+        let mut units = 0;
+        let mut blocked = false;
+        let first = Frame::new("0 units of sand", cave.grid.map(Cell::symbol));
+        let pour = std::iter::from_fn(move || {
+            if blocked {
+                return None;
+            }
+            let sand = drop_sand(&cave)?;
+            cave.set(&sand, Cell::Sand);
+            units += 1;
+            blocked = sand == ORIGIN;
+            let caption = format!("{} units of sand", units);
+            Some(Frame::new(caption, cave.grid.map(Cell::symbol)))
+        });
+        Box::new(std::iter::once(first).chain(pour))
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let mut cave = parse_input(input);
+    Some(pour_sand(&mut cave))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut cave = parse_input(input);
+
+    Some(pour_sand_part_2(&mut cave))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 14);
+        assert_eq!(part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 14);
+        assert_eq!(part_two(&input), Some(93));
+    }
+
+    #[test]
+    fn test_render() {
+        let input = crate::read_file("examples", 14);
+        let mut cave = parse_input(&input);
+        crate::assert_snapshot!(14, "rocks", cave.to_string());
+        pour_sand(&mut cave);
+        crate::assert_snapshot!(14, "sand", cave.to_string());
+
+        let mut cave = parse_input(&input);
+        pour_sand_part_2(&mut cave);
+        crate::assert_snapshot!(14, "sand-floor", cave.to_string());
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = crate::read_file("examples", 14);
+        for (floor, units) in [(false, 24), (true, 93)] {
+            let last = SandAnimation::new(&input, floor).frames().last().unwrap();
+            assert_eq!(last.caption, format!("{} units of sand", units));
+        }
+        let mut cave = parse_input(&input);
+        pour_sand(&mut cave);
+        let animation = SandAnimation::new(&input, false);
+        assert_eq!(animation.frames().last().unwrap().cells.to_string(), cave.to_string());
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(14, part_one, part_two);
+    }
+}
@@ -0,0 +1,124 @@
+
+use std::ops::{Range};
+use itertools::Itertools;
+use crate::helpers::parse::ints_n;
+use crate::helpers::{IntervalSet, Point2};
+
+
+pub type Point = Point2<i64>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    coords: Point,
+    beacon: Point,
+    beacon_distance: i64,
+}
+
+impl Sensor {
+    fn coverage_at_line_y(&self, y: i64) -> Option<Range<i64>> {
+        let distance_from_sensor = (y - self.coords.y).abs(); // distance from sensor to line
+        let half_x_range = self.beacon_distance - distance_from_sensor; // half of the x range of the line y
+        // - the further the sensor from the line, the smaller the x range
+        // because we use manhattan distance, every `y` distance from sensor reduces the width range by 1
+        match half_x_range {
+            _ if half_x_range < 0 => None, //Doesnt cover line at all
+            _ => Some((self.coords.x - half_x_range)..(self.coords.x + half_x_range + 1)), //Covers a certain range [x - half_x_range, x + half_x_range]
+        }
+    }
+}
+
+
+pub fn coverage_at_line_y(sensors: &[Sensor], y: i64) -> IntervalSet<i64> {
+    sensors.iter()
+        .filter_map(|sensor| sensor.coverage_at_line_y(y))
+        .collect()
+}
+
+pub fn parse_sensors(input: &str) -> Vec<Sensor> {
+    let mut sensors: Vec<Sensor> = Vec::new();
+    for line in input.lines() {
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = ints_n::<i64, 4>(line).unwrap();
+        let current_sensor = Point::new(sensor_x, sensor_y);
+        let closest_beacon = Point::new(beacon_x, beacon_y);
+
+        let distance = current_sensor.manhattan_distance(&closest_beacon);
+        let sensor_data = Sensor {
+            coords: current_sensor,
+            beacon: closest_beacon,
+            beacon_distance: distance,
+        };
+        sensors.push(sensor_data);
+    }
+    sensors
+}
+
+pub fn solve_one(input: &str, row: i64) -> Option<i64> {
+    let sensors = parse_sensors(input);
+
+    let area = coverage_at_line_y(&sensors, row).len();
+    let beacons_in_row: i64 = sensors.iter().filter(|sensor| sensor.beacon.y == row).map(|sensor| sensor.beacon.x).dedup().count() as i64;
+    Some(area - beacons_in_row)
+}
+
+pub fn solve_two(input: &str, scan_range: Range<i64>) -> Option<i64> {
+    let sensors = parse_sensors(input);
+
+    let possible_points: Vec<Option<Point>> = scan_range.clone().map(|row| {
+        // if the whole scanned line is covered there's no room for an extra beacon,
+        // otherwise the first gap in the coverage is where the beacon is.
+        coverage_at_line_y(&sensors, row)
+            .gaps(scan_range.clone())
+            .first()
+            .map(|gap| Point::new(gap.start, row))
+        // only keep the Some values
+    }).filter(|x| x.is_some()).collect_vec();
+    match possible_points {
+        // shouldn't happen if the problem is well formed
+        _ if possible_points.is_empty() => None,
+        _ => {
+            // the first point should be the solution :))
+            let target_point = possible_points[0].unwrap();
+            Some(target_point.x * 4000000 + target_point.y)
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    solve_one(input, 2000000)
+}
+
+pub fn part_one_test(input: &str) -> Option<i64> {
+    solve_one(input, 10)
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let scan_range: Range<i64> = 0..(4000000 + 1);
+    solve_two(input, scan_range)
+}
+
+pub fn part_two_test(input: &str) -> Option<i64> {
+    let scan_range: Range<i64> = 0..20 + 1;
+    solve_two(input, scan_range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_one_test(&input), Some(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_two_test(&input), Some(56000011));
+    }
+
+    #[test]
+    fn test_inputs() {
+        crate::inputs::assert_all(15, part_one, part_two);
+    }
+}
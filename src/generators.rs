@@ -0,0 +1,588 @@
+/*
+ * Random puzzle input generators, used to stress and cross-check solutions.
+ * Every generator takes a seeded rng and a size hint and returns a valid puzzle input.
+ * `cargo gen <day> --size <size> --seed <seed>` prints one of them.
+ */
+use crate::helpers::rng::Rng;
+
+/// Signature shared by every generator.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The generator for `day`, if there is one.
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        9 => day09,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        15 => day15,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+fn join(lines: impl Iterator<Item = String>, separator: &str) -> String {
+    lines.collect::<Vec<_>>().join(separator)
+}
+
+/// `size` elves carrying 1 to 6 snacks each, one blank line between elves.
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    join(
+        (0..size).map(|_| {
+            let items = 1 + rng.index(6);
+            join((0..items).map(|_| rng.range(1000..60000).to_string()), "\n")
+        }),
+        "\n\n",
+    )
+}
+
+/// `size` rounds of rock paper scissors.
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    join(
+        (0..size).map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        }),
+        "\n",
+    )
+}
+
+/// `size` groups of three rucksacks. Each rucksack has exactly one item type in both
+/// compartments and each group exactly one item type common to its three rucksacks.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = Vec::with_capacity(3 * size);
+    for _ in 0..size {
+        let mut pool = items.clone();
+        rng.shuffle(&mut pool);
+        let badge = pool.pop().unwrap();
+        // every rucksack draws from its own 17 item types so that the badge is the only common one
+        for own in pool.chunks(17) {
+            let (shared, left_only, right_only) = (own[0], &own[1..9], &own[9..17]);
+            let length = 2 + rng.index(15);
+            let mut left = vec![shared];
+            let mut right = vec![shared];
+            match rng.chance(1, 2) {
+                true => left.push(badge),
+                false => right.push(badge),
+            }
+            while left.len() < length {
+                left.push(*rng.choose(left_only));
+            }
+            while right.len() < length {
+                right.push(*rng.choose(right_only));
+            }
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            lines.push(left.into_iter().chain(right).collect::<String>());
+        }
+    }
+    lines.join("\n")
+}
+
+/// `size` pairs of section assignments, including single sections and identical ranges.
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    let assignment = |rng: &mut Rng| {
+        let start = rng.range(1..100);
+        let end = match rng.chance(1, 8) {
+            true => start,
+            false => rng.range(start..100),
+        };
+        (start, end)
+    };
+    join(
+        (0..size).map(|_| {
+            let first = assignment(rng);
+            let second = match rng.chance(1, 10) {
+                true => first,
+                false => assignment(rng),
+            };
+            format!("{}-{},{}-{}", first.0, first.1, second.0, second.1)
+        }),
+        "\n",
+    )
+}
+
+/// Up to 9 stacks, some of them possibly empty, followed by `size` moves that never
+/// take more crates than the source stack holds.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    let stack_count = 1 + rng.index(9);
+    let mut stacks: Vec<Vec<char>> = (0..stack_count)
+        .map(|_| {
+            let height = match rng.chance(1, 5) {
+                true => 0,
+                false => 1 + rng.index(8),
+            };
+            (0..height)
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+
+    let tallest = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..tallest)
+        .rev()
+        .map(|level| {
+            let cells = stacks.iter().map(|stack| match stack.get(level) {
+                Some(item) => format!("[{}]", item),
+                None => "   ".to_string(),
+            });
+            join(cells, " ").trim_end().to_string()
+        })
+        .collect();
+    lines.push(join((1..=stack_count).map(|id| format!(" {} ", id)), " "));
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let sources: Vec<usize> = (0..stack_count)
+            .filter(|&id| !stacks[id].is_empty())
+            .collect();
+        if sources.is_empty() || stack_count == 1 {
+            break;
+        }
+        let from = *rng.choose(&sources);
+        let mut to = rng.index(stack_count - 1);
+        if to >= from {
+            to += 1;
+        }
+        let amount = 1 + rng.index(stacks[from].len());
+        let remaining = stacks[from].len() - amount;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+        lines.push(format!("move {} from {} to {}", amount, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+/// A datastream of `size` characters (at least 14) drawn from a few letters, with a run of
+/// 14 distinct characters somewhere in it.
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let length = size.max(14);
+    let alphabet: Vec<char> = ('a'..='z').collect();
+    let mut stream: Vec<char> = (0..length).map(|_| alphabet[rng.index(4)]).collect();
+    let mut marker = alphabet.clone();
+    rng.shuffle(&mut marker);
+    let start = rng.index(length - 13);
+    stream[start..start + 14].copy_from_slice(&marker[..14]);
+    stream.into_iter().collect()
+}
+
+struct Day07Tree {
+    children: Vec<Vec<(String, usize)>>,
+    files: Vec<Vec<(String, u64)>>,
+}
+
+fn day07_visit(
+    rng: &mut Rng,
+    tree: &Day07Tree,
+    dir: usize,
+    path: &mut Vec<String>,
+    lines: &mut Vec<String>,
+) {
+    lines.push("$ ls".to_string());
+    let mut listing: Vec<String> = tree.children[dir]
+        .iter()
+        .map(|(name, _)| format!("dir {}", name))
+        .chain(
+            tree.files[dir]
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+    lines.extend(listing);
+
+    for (name, child) in &tree.children[dir] {
+        lines.push(format!("$ cd {}", name));
+        path.push(name.clone());
+        day07_visit(rng, tree, *child, path, lines);
+        path.pop();
+        // back to `dir`, either one level up or all the way down again from the root
+        if rng.chance(1, 4) {
+            lines.push("$ cd /".to_string());
+            lines.extend(path.iter().map(|name| format!("$ cd {}", name)));
+        } else {
+            lines.push("$ cd ..".to_string());
+        }
+    }
+}
+
+/// A terminal session exploring `size` directories. The disk usage stays between the 40M needed
+/// for part two to make sense and the 70M disk size. Directory names repeat across the tree,
+/// and the session goes back with both `cd /` and `cd ..`, including `cd ..` at the root.
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    const NAMES: [&str; 6] = ["a", "b", "d", "e", "jqp", "bfqzjjct"];
+    let count = size.max(1);
+    let mut tree = Day07Tree {
+        children: vec![Vec::new(); count],
+        files: vec![Vec::new(); count],
+    };
+    for dir in 1..count {
+        let parent = rng.index(dir);
+        let name = match *rng.choose(&NAMES) {
+            name if tree.children[parent].iter().any(|(n, _)| n == name) => {
+                format!("{}{}", name, dir)
+            }
+            name => name.to_string(),
+        };
+        tree.children[parent].push((name, dir));
+    }
+
+    // log-uniform weights, scaled so the total lands in the valid range
+    let weights: Vec<Vec<u64>> = (0..count)
+        .map(|_| {
+            (0..rng.index(5))
+                .map(|_| {
+                    let magnitude = 10u64.pow(1 + rng.below(6) as u32);
+                    1 + rng.below(magnitude)
+                })
+                .collect()
+        })
+        .collect();
+    let weight_sum: u64 = weights.iter().flatten().sum::<u64>().max(1);
+    let total = 40_000_001 + rng.below(29_000_000);
+    let mut used = 0;
+    for (dir, dir_weights) in weights.iter().enumerate() {
+        for (i, weight) in dir_weights.iter().enumerate() {
+            let name = format!("{}.{}", rng.choose(&NAMES), ["txt", "dat", "log", "lst"][i]);
+            let size = (total * weight / weight_sum).max(1);
+            used += size;
+            tree.files[dir].push((name, size));
+        }
+    }
+    // whatever rounding left out goes in a file at the root
+    if used < total {
+        tree.files[0].push(("rest.dat".to_string(), total - used));
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    if rng.chance(1, 2) {
+        lines.push("$ cd ..".to_string());
+    }
+    day07_visit(rng, &tree, 0, &mut Vec::new(), &mut lines);
+    lines.join("\n")
+}
+
+/// A square forest of `size` x `size` tree heights.
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    join(
+        (0..size).map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect::<String>()
+        }),
+        "\n",
+    )
+}
+
+/// `size` head motions of 1 to 20 steps. The rope wanders freely, negative coordinates included.
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    join(
+        (0..size).map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                1 + rng.below(20)
+            )
+        }),
+        "\n",
+    )
+}
+
+/// A program of at least `size` instructions lasting at least the 240 cycles of the CRT,
+/// keeping the sprite within the screen.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let (mut x, mut cycles) = (1, 0);
+    let mut lines = Vec::new();
+    while cycles < 240 || lines.len() < size {
+        if rng.chance(1, 3) {
+            lines.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let value = rng.range(-1 - x..41 - x);
+            x += value;
+            lines.push(format!("addx {}", value));
+            cycles += 2;
+        }
+    }
+    lines.join("\n")
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: (char, Option<u64>),
+    divisor: u64,
+    targets: (usize, usize),
+}
+
+impl Monkey {
+    fn to_text(&self, id: usize) -> String {
+        let items = join(self.items.iter().map(u64::to_string), ", ");
+        let operand = self
+            .operation
+            .1
+            .map_or("old".to_string(), |n| n.to_string());
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+            id, items, self.operation.0, operand, self.divisor, self.targets.0, self.targets.1
+        )
+    }
+}
+
+/// Whether the 20 rounds of part one run without any worry level overflowing.
+fn day11_fits(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..20 {
+        for (id, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[id]) {
+                let operand = monkey.operation.1.unwrap_or(item);
+                let worry = match monkey.operation.0 {
+                    '+' => item.checked_add(operand),
+                    _ => item.checked_mul(operand),
+                };
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return false;
+                };
+                let target = match worry % monkey.divisor {
+                    0 => monkey.targets.0,
+                    _ => monkey.targets.1,
+                };
+                items[target].push(worry);
+            }
+        }
+    }
+    true
+}
+
+/// 2 to 9 monkeys holding about `size` items each. Divisors are distinct primes so that the
+/// worry levels of part two stay small once reduced, and candidates whose part one would
+/// overflow are drawn again.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    loop {
+        let count = 2 + rng.index(8);
+        let mut divisors = PRIMES.to_vec();
+        rng.shuffle(&mut divisors);
+        let monkeys: Vec<Monkey> = (0..count)
+            .map(|id| {
+                let other = |rng: &mut Rng| (id + 1 + rng.index(count - 1)) % count;
+                Monkey {
+                    items: (0..1 + rng.index(size.max(1)))
+                        .map(|_| 50 + rng.below(50))
+                        .collect(),
+                    operation: match rng.index(4) {
+                        0 => ('*', None),
+                        1 => ('*', Some(2 + rng.below(18))),
+                        _ => ('+', Some(1 + rng.below(8))),
+                    },
+                    divisor: divisors[id],
+                    targets: (other(rng), other(rng)),
+                }
+            })
+            .collect();
+        if day11_fits(&monkeys) {
+            let texts = monkeys
+                .iter()
+                .enumerate()
+                .map(|(id, monkey)| monkey.to_text(id));
+            return join(texts, "\n\n");
+        }
+    }
+}
+
+/// A heightmap `size` rows tall and at least 52 columns wide, sloping up from `S` on the left
+/// edge to `E`, with random dips. A straight path from `S` to `E` is kept climbable and the
+/// left column is all `a`.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = ((2 * size).max(52), size.max(1));
+    let end = (width / 2 + rng.index(width / 2), rng.index(height));
+    let start = (0, rng.index(height));
+    let distance = |(x, y): (usize, usize)| x.abs_diff(end.0) + y.abs_diff(end.1);
+    let slope = distance(start);
+    // along the start row, then up or down the end column
+    let on_path = |(x, y): (usize, usize)| {
+        (y == start.1 && x <= end.0)
+            || (x == end.0 && (start.1.min(end.1)..=start.1.max(end.1)).contains(&y))
+    };
+    join(
+        (0..height).map(|y| {
+            (0..width)
+                .map(|x| {
+                    let level = 25 - (distance((x, y)) * 25 / slope).min(25);
+                    let level = match x {
+                        0 => 0,
+                        _ if !on_path((x, y)) && rng.chance(1, 4) => {
+                            rng.below(level as u64 + 1) as usize
+                        }
+                        _ => level,
+                    };
+                    match (x, y) {
+                        position if position == start => 'S',
+                        position if position == end => 'E',
+                        _ => char::from(b'a' + level as u8),
+                    }
+                })
+                .collect::<String>()
+        }),
+        "\n",
+    )
+}
+
+fn day13_packet(rng: &mut Rng, depth: usize) -> String {
+    let length = rng.index(5);
+    let elements = (0..length).map(|_| match depth < 4 && rng.chance(1, 3) {
+        true => day13_packet(rng, depth + 1),
+        false => rng.below(11).to_string(),
+    });
+    format!("[{}]", join(elements, ","))
+}
+
+/// `size` pairs of nested packets, the second one sometimes derived from the first so that
+/// comparisons go deep.
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    join(
+        (0..size).map(|_| {
+            let left = day13_packet(rng, 0);
+            // a longer copy of the left packet is compared all the way to its end
+            let right = match (left.as_str(), rng.chance(1, 3)) {
+                ("[]", true) => "[[]]".to_string(),
+                (_, true) => format!("{},{}]", &left[..left.len() - 1], rng.below(11)),
+                (_, false) => day13_packet(rng, 0),
+            };
+            format!("{}\n{}", left, right)
+        }),
+        "\n\n",
+    )
+}
+
+/// `size` rock paths of 2 to 5 points, spreading wider and deeper as `size` grows so that the
+/// floor of part two reaches negative x.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    let spread = 10 + size as i64;
+    let depth = 2 + 2 * size as i64;
+    join(
+        (0..size).map(|_| {
+            let mut point = (
+                500 + rng.range(-spread..spread + 1),
+                1 + rng.range(0..depth),
+            );
+            let mut points = vec![point];
+            let horizontal_first = rng.chance(1, 2);
+            for i in 0..1 + rng.index(4) {
+                let step = rng.range(1..7) * [-1, 1][rng.index(2)];
+                if (i % 2 == 0) == horizontal_first {
+                    point.0 += step;
+                } else {
+                    point.1 = (point.1 + step).max(1);
+                }
+                if points.last() != Some(&point) {
+                    points.push(point);
+                }
+            }
+            if points.len() == 1 {
+                points.push((point.0 + 1, point.1));
+            }
+            join(points.iter().map(|(x, y)| format!("{},{}", x, y)), " -> ")
+        }),
+        "\n",
+    )
+}
+
+/// A hidden beacon spot in the 0..=4000000 square, with a sensor far away on each diagonal so
+/// that together they cover everything else, plus `size` random sensors that all stop just short of it.
+/// Beacons are random points on each sensor's range and aren't checked against other sensors.
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    const LIMIT: i64 = 4_000_000;
+    let hidden = (rng.range(0..LIMIT + 1), rng.range(0..LIMIT + 1));
+    let diagonal = LIMIT + 1 + rng.range(0..1000);
+    let mut sensors: Vec<(i64, i64)> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .iter()
+        .map(|(dx, dy)| (hidden.0 + dx * diagonal, hidden.1 + dy * diagonal))
+        .collect();
+    sensors.extend((0..size).map(|_| loop {
+        let sensor = (rng.range(0..LIMIT + 1), rng.range(0..LIMIT + 1));
+        if sensor != hidden {
+            break sensor;
+        }
+    }));
+    rng.shuffle(&mut sensors);
+    join(
+        sensors.into_iter().map(|sensor| {
+            let reach = sensor.0.abs_diff(hidden.0) + sensor.1.abs_diff(hidden.1) - 1;
+            let reach = reach as i64;
+            // random point at exactly `reach` from the sensor
+            let dx = rng.range(-reach..reach + 1);
+            let dy = (reach - dx.abs()) * [-1, 1][rng.index(2)];
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0,
+                sensor.1,
+                sensor.0 + dx,
+                sensor.1 + dy
+            )
+        }),
+        "\n",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_reproducible() {
+        for day in 1..=15 {
+            let generate = generator(day).unwrap();
+            for size in [1, 2, 10] {
+                let input = generate(&mut Rng::new(7), size);
+                assert!(!input.trim().is_empty(), "day {} size {}", day, size);
+                assert_eq!(input, generate(&mut Rng::new(7), size));
+            }
+        }
+        assert!(generator(16).is_none());
+    }
+
+    #[test]
+    fn test_day03_common_items() {
+        let items = |s: &str| s.chars().collect::<HashSet<_>>();
+        let input = day03(&mut Rng::new(3), 50);
+        let lines: Vec<&str> = input.lines().collect();
+        for line in &lines {
+            let (left, right) = line.split_at(line.len() / 2);
+            assert_eq!(items(left).intersection(&items(right)).count(), 1);
+        }
+        for group in lines.chunks(3) {
+            let common: HashSet<char> = items(group[0])
+                .intersection(&items(group[1]))
+                .copied()
+                .collect();
+            assert_eq!(common.intersection(&items(group[2])).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_day07_disk_usage() {
+        for seed in 0..20 {
+            let input = day07(&mut Rng::new(seed), 30);
+            let used: u64 = input
+                .lines()
+                .filter_map(|line| line.split(' ').next()?.parse::<u64>().ok())
+                .sum();
+            assert!((40_000_001..=70_000_000).contains(&used), "{}", used);
+        }
+    }
+}
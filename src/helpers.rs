@@ -2,3 +2,15 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod differential;
+pub mod grid;
+pub mod interval;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod rng;
+pub mod search;
+
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use point::{BoundingBox, Direction, Direction8, Point2, Vec2};
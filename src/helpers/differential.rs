@@ -0,0 +1,339 @@
+/*
+ * Differential testing: run several implementations of the same part on random inputs
+ * and report the first input on which they disagree, shrunk to as few lines as possible.
+ *
+ * Typical use from a solution's tests:
+ *
+ *     Differential::new(generate_input)
+ *         .implementation("sort", part_one_naive)
+ *         .implementation("streaming", part_one)
+ *         .assert_agree();
+ *
+ * `DIFF_CASES`, `DIFF_SIZE` and `DIFF_SEED` override the number of cases, the maximum input
+ * size and the seed, e.g. `DIFF_CASES=100000 cargo test --release day01::tests::test_differential`.
+ */
+use std::cell::Cell;
+use std::env;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use super::rng::Rng;
+
+/// Produces a puzzle input from a seeded rng and a size hint.
+pub type Generator<'a> = Box<dyn Fn(&mut Rng, usize) -> String + 'a>;
+
+type Implementation<'a, T> = (&'static str, Box<dyn Fn(&str) -> T + 'a>);
+
+/// Result of running one implementation: its answer, or the panic message.
+pub type Outcome<T> = Result<T, String>;
+
+pub struct Differential<'a, T> {
+    generator: Generator<'a>,
+    implementations: Vec<Implementation<'a, T>>,
+    cases: usize,
+    max_size: usize,
+    seed: u64,
+}
+
+/// First disagreement found, with the input shrunk while the implementations still disagree.
+#[derive(Debug)]
+pub struct Disagreement<T> {
+    pub case: usize,
+    pub seed: u64,
+    pub original_input: String,
+    pub input: String,
+    pub outcomes: Vec<(&'static str, Outcome<T>)>,
+}
+
+/// Total time spent by each implementation when every case agreed.
+#[derive(Debug)]
+pub struct Report {
+    pub cases: usize,
+    pub timings: Vec<(&'static str, Duration)>,
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+thread_local! {
+    /// Whether panics on this thread are expected and reported by a run, see `quiet_panics`.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` without printing the panics it catches. The panic hook is process-wide, so it is
+/// replaced once by one that stays quiet on threads inside a run and otherwise defers to the
+/// previous hook: runs of tests in parallel never swap it under each other's feet.
+fn quiet_panics<R>(f: impl FnOnce() -> R) -> R {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+    let was_quiet = QUIET.with(|quiet| quiet.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|quiet| quiet.set(was_quiet));
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Runs `solver`, turning a panic into its message.
+fn outcome<T>(solver: impl Fn(&str) -> T, input: &str) -> Outcome<T> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+impl<'a, T: PartialEq + Debug> Differential<'a, T> {
+    /// `generator` receives a seeded rng and a size hint, and must return a valid puzzle input.
+    pub fn new(generator: impl Fn(&mut Rng, usize) -> String + 'a) -> Differential<'a, T> {
+        Differential {
+            generator: Box::new(generator),
+            implementations: Vec::new(),
+            cases: env_or("DIFF_CASES", 1000),
+            max_size: env_or("DIFF_SIZE", 20),
+            seed: env_or("DIFF_SEED", 2022),
+        }
+    }
+
+    pub fn implementation(mut self, name: &'static str, solver: impl Fn(&str) -> T + 'a) -> Self {
+        self.implementations.push((name, Box::new(solver)));
+        self
+    }
+
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn outcomes(&self, input: &str) -> Vec<(&'static str, Outcome<T>)> {
+        self.implementations
+            .iter()
+            .map(|(name, solver)| (*name, outcome(solver, input)))
+            .collect()
+    }
+
+    fn agree(outcomes: &[(&'static str, Outcome<T>)]) -> bool {
+        outcomes.windows(2).all(|pair| pair[0].1 == pair[1].1)
+    }
+
+    /// Still a useful counterexample: every implementation returns, but not the same answer.
+    fn disagree_cleanly(&self, input: &str) -> bool {
+        let outcomes = self.outcomes(input);
+        outcomes.iter().all(|(_, outcome)| outcome.is_ok()) && !Self::agree(&outcomes)
+    }
+
+    /// Delta debugging over lines: drop ever smaller chunks of lines as long as the
+    /// implementations keep disagreeing without panicking on the reduced input.
+    fn shrink(&self, input: &str) -> String {
+        let mut lines: Vec<&str> = input.lines().collect();
+        let mut chunk = lines.len() / 2;
+        while chunk > 0 {
+            let mut start = 0;
+            let mut removed_any = false;
+            while start < lines.len() {
+                let end = (start + chunk).min(lines.len());
+                let candidate = [&lines[..start], &lines[end..]].concat();
+                if !candidate.is_empty() && self.disagree_cleanly(&candidate.join("\n")) {
+                    lines = candidate;
+                    removed_any = true;
+                } else {
+                    start += chunk;
+                }
+            }
+            if !removed_any {
+                chunk /= 2;
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Runs every implementation on `cases` generated inputs, sizes cycling from 1 up to
+    /// `max_size` so that small counterexamples are found first.
+    pub fn run(&self) -> Result<Report, Box<Disagreement<T>>> {
+        // panics of the implementations are part of the report, and every shrinking step
+        // would print one otherwise
+        quiet_panics(|| self.run_cases())
+    }
+
+    fn run_cases(&self) -> Result<Report, Box<Disagreement<T>>> {
+        let mut timings: Vec<(&'static str, Duration)> = self
+            .implementations
+            .iter()
+            .map(|(name, _)| (*name, Duration::ZERO))
+            .collect();
+
+        for case in 0..self.cases {
+            let seed = self.seed.wrapping_add(case as u64);
+            let size = 1 + case % self.max_size.max(1);
+            let input = (self.generator)(&mut Rng::new(seed), size);
+
+            let mut outcomes = Vec::with_capacity(self.implementations.len());
+            for (index, (name, solver)) in self.implementations.iter().enumerate() {
+                let timer = Instant::now();
+                let outcome = outcome(solver, &input);
+                timings[index].1 += timer.elapsed();
+                outcomes.push((*name, outcome));
+            }
+
+            if !Self::agree(&outcomes) {
+                let shrunk = self.shrink(&input);
+                let outcomes = self.outcomes(&shrunk);
+                return Err(Box::new(Disagreement {
+                    case,
+                    seed,
+                    original_input: input,
+                    input: shrunk,
+                    outcomes,
+                }));
+            }
+        }
+        Ok(Report {
+            cases: self.cases,
+            timings,
+        })
+    }
+
+    /// Runs the cases and panics with a readable report on the first disagreement.
+    #[track_caller]
+    pub fn assert_agree(&self) -> Report {
+        match self.run() {
+            Ok(report) => report,
+            Err(disagreement) => panic!("{}", disagreement),
+        }
+    }
+}
+
+impl<T: Debug> Display for Disagreement<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "implementations disagree on case {} (seed {}), shrunk from {} to {} lines:",
+            self.case,
+            self.seed,
+            self.original_input.lines().count(),
+            self.input.lines().count()
+        )?;
+        writeln!(f, "---\n{}\n---", self.input)?;
+        for (name, outcome) in &self.outcomes {
+            match outcome {
+                Ok(answer) => writeln!(f, "{}: {:?}", name, answer)?,
+                Err(message) => writeln!(f, "{}: panicked ({})", name, message)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} cases agreed", self.cases)?;
+        for (name, total) in &self.timings {
+            writeln!(
+                f,
+                "{}: {:.2?} total, {:.2?} per case",
+                name,
+                total,
+                *total / self.cases.max(1) as u32
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| rng.range(0..100).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn sum(input: &str) -> i64 {
+        input.lines().map(|l| l.parse::<i64>().unwrap()).sum()
+    }
+
+    #[test]
+    fn test_agreeing_implementations() {
+        let report = Differential::new(numbers)
+            .implementation("lines", sum)
+            .implementation("fold", |input: &str| {
+                input
+                    .split('\n')
+                    .fold(0, |acc, l| acc + l.parse::<i64>().unwrap())
+            })
+            .cases(200)
+            .run()
+            .unwrap();
+        assert_eq!(report.cases, 200);
+        assert_eq!(report.timings.len(), 2);
+    }
+
+    #[test]
+    fn test_disagreement_is_shrunk() {
+        // wrong as soon as a number above 90 shows up
+        let buggy = |input: &str| {
+            input
+                .lines()
+                .map(|l| l.parse::<i64>().unwrap())
+                .filter(|&n| n <= 90)
+                .sum::<i64>()
+        };
+        let disagreement = Differential::new(numbers)
+            .implementation("reference", sum)
+            .implementation("buggy", buggy)
+            .max_size(30)
+            .run()
+            .unwrap_err();
+        assert_eq!(disagreement.input.lines().count(), 1);
+        assert!(disagreement.input.parse::<i64>().unwrap() > 90);
+        assert!(disagreement.to_string().contains("buggy: "));
+    }
+
+    #[test]
+    fn test_panic_message_is_reported() {
+        let fragile = |input: &str| {
+            if input.lines().any(|l| l == "0") {
+                panic!("no zero allowed");
+            }
+            sum(input)
+        };
+        let disagreement = Differential::new(numbers)
+            .implementation("reference", sum)
+            .implementation("fragile", fragile)
+            .max_size(30)
+            .run()
+            .unwrap_err();
+        // inputs that make an implementation panic aren't shrunk
+        assert!(disagreement.input.lines().any(|l| l == "0"));
+        assert_eq!(disagreement.outcomes[1].1, Err("no zero allowed".to_string()));
+        assert!(disagreement
+            .to_string()
+            .contains("fragile: panicked (no zero allowed)"));
+    }
+}
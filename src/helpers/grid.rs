@@ -0,0 +1,301 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use super::point::Point2;
+
+/// Offsets of the 4 orthogonal neighbours, clockwise starting from up.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all 8 neighbours, clockwise starting from up.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A fixed-size 2D grid stored row by row in a single `Vec`.
+/// Cells are addressed as `(x, y)`, with `(0, 0)` in the top-left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid from a block of text, one row per line, mapping every char with `cell`.
+    /// Panics if the lines don't all have the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Grid<T> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let len_before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row_width = cells.len() - len_before;
+            if height == 0 {
+                width = row_width;
+            }
+            assert_eq!(
+                row_width,
+                width,
+                "line {} has a different width",
+                height + 1
+            );
+            height += 1;
+        }
+        Grid::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `(x, y)` by `(dx, dy)`, returning `None` when leaving the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let (nx, ny) = (x as isize + dx, y as isize + dy);
+        self.contains(nx, ny).then_some((nx as usize, ny as usize))
+    }
+
+    /// Up, right, down and left neighbours of `(x, y)` that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&offset| self.step((x, y), offset))
+    }
+
+    /// All 8 neighbours of `(x, y)`, diagonals included, that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&offset| self.step((x, y), offset))
+    }
+
+    /// Positions walked from `(x, y)` in direction `(dx, dy)` until the edge, start excluded.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut current = Some((x, y));
+        std::iter::from_fn(move || {
+            current = self.step(current?, (dx, dy));
+            current
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell of the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell (row by row) matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal: `(x, y)` becomes `(y, x)`.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// Renders one line per row, each cell using its own `Display`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn test_lines_and_rays() {
+        let grid = sample();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.ray(0, 0, (1, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0)]
+        );
+        assert_eq!(grid.ray(0, 0, (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::Range;
+
+use super::point::Coordinate;
+
+/// A set of integers stored as sorted, disjoint half-open ranges.
+/// Overlapping or touching ranges are merged on insertion, so `0..3` and `3..5`
+/// are kept as a single `0..5`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Converts a `start..=end` pair, as found in puzzle inputs, to the half-open range used here.
+    /// `end` must be below `T::MAX`, which has no half-open end: widen `T` for such bounds, e.g.
+    /// `u64` for `u32` sections.
+    pub fn inclusive(start: T, end: T) -> Range<T> {
+        assert!(end < T::MAX, "the range ending at T::MAX has no half-open end");
+        start..end + T::ONE
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |acc, range| acc + (range.end - range.start))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        let first = self.ranges.partition_point(|r| r.end < start);
+        let mut last = first;
+        while last < self.ranges.len() && self.ranges[last].start <= end {
+            start = start.min(self.ranges[last].start);
+            end = end.max(self.ranges[last].end);
+            last += 1;
+        }
+        self.ranges.splice(first..last, [start..end]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let mut last = first;
+        let mut remainders = Vec::with_capacity(2);
+        while last < self.ranges.len() && self.ranges[last].start < range.end {
+            let overlapped = &self.ranges[last];
+            if overlapped.start < range.start {
+                remainders.push(overlapped.start..range.start);
+            }
+            if overlapped.end > range.end {
+                remainders.push(range.end..overlapped.end);
+            }
+            last += 1;
+        }
+        self.ranges.splice(first..last, remainders);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(index).is_some_and(|r| r.start <= value)
+    }
+
+    /// Whether every integer of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    /// Whether at least one integer of `range` is in the set.
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(index).is_some_and(|r| r.start < range.end)
+    }
+
+    pub fn is_subset(&self, other: &IntervalSet<T>) -> bool {
+        self.ranges.iter().all(|range| other.contains_range(range))
+    }
+
+    pub fn is_disjoint(&self, other: &IntervalSet<T>) -> bool {
+        !self.ranges.iter().any(|range| other.overlaps(range))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        other
+            .ranges
+            .iter()
+            .for_each(|range| union.insert(range.clone()));
+        union
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        other
+            .ranges
+            .iter()
+            .for_each(|range| difference.remove(range.clone()));
+        difference
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The maximal ranges within `bound` that the set does not cover.
+    pub fn gaps(&self, bound: Range<T>) -> Vec<Range<T>> {
+        let mut gaps = Vec::new();
+        let mut cursor = bound.start;
+        let first = self.ranges.partition_point(|r| r.end <= bound.start);
+        for range in self.ranges[first..]
+            .iter()
+            .take_while(|r| r.start < bound.end)
+        {
+            if range.start > cursor {
+                gaps.push(cursor..range.start);
+            }
+            cursor = cursor.max(range.end);
+        }
+        if cursor < bound.end {
+            gaps.push(cursor..bound.end);
+        }
+        gaps
+    }
+}
+
+impl<T: Coordinate> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> IntervalSet<T> {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by_key(|r| r.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+}
+
+impl<T: Coordinate> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> IntervalSet<T> {
+        IntervalSet::from_iter([range])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::rng::Rng;
+
+    const UNIVERSE: usize = 64;
+
+    /// Naive model of a set over `0..UNIVERSE`, one bool per value.
+    fn bitmap(set: &IntervalSet<usize>) -> Vec<bool> {
+        (0..UNIVERSE).map(|v| set.contains(v)).collect()
+    }
+
+    fn bitmap_of(ranges: &[Range<usize>]) -> Vec<bool> {
+        (0..UNIVERSE)
+            .map(|v| ranges.iter().any(|r| r.contains(&v)))
+            .collect()
+    }
+
+    fn random_ranges(rng: &mut Rng, count: usize) -> Vec<Range<usize>> {
+        (0..count)
+            .map(|_| {
+                let (a, b) = (rng.index(UNIVERSE), rng.index(UNIVERSE));
+                a.min(b)..a.max(b)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = IntervalSet::new();
+        set.insert(0..3);
+        set.insert(5..8);
+        set.insert(3..5);
+        assert_eq!(set, IntervalSet::from(0..8));
+        assert_eq!(set.len(), 8);
+        set.remove(2..4);
+        assert_eq!(set.ranges(), &[0..2, 4..8]);
+        assert_eq!(set.gaps(0..10), vec![2..4, 8..10]);
+    }
+
+    #[test]
+    fn test_inclusive() {
+        assert_eq!(IntervalSet::inclusive(2_u8, 4), 2..5);
+        assert_eq!(IntervalSet::inclusive(0_u8, 254), 0..255);
+        let max = u32::MAX as u64;
+        assert_eq!(IntervalSet::inclusive(max, max), max..max + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "no half-open end")]
+    fn test_inclusive_at_max() {
+        IntervalSet::inclusive(0_u8, u8::MAX);
+    }
+
+    #[test]
+    fn test_against_bitmap() {
+        let mut rng = Rng::new(2022);
+        for _ in 0..500 {
+            let inserted = random_ranges(&mut rng, 6);
+            let removed = random_ranges(&mut rng, 2);
+            let other = random_ranges(&mut rng, 4);
+
+            let mut set = IntervalSet::new();
+            inserted.iter().for_each(|r| set.insert(r.clone()));
+            assert_eq!(set, inserted.iter().cloned().collect());
+            removed.iter().for_each(|r| set.remove(r.clone()));
+            let other_set: IntervalSet<usize> = other.iter().cloned().collect();
+
+            let expected: Vec<bool> = bitmap_of(&inserted)
+                .into_iter()
+                .zip(bitmap_of(&removed))
+                .map(|(inserted, removed)| inserted && !removed)
+                .collect();
+            assert_eq!(bitmap(&set), expected);
+            assert_eq!(set.len(), expected.iter().filter(|&&b| b).count());
+
+            let other_bits = bitmap(&other_set);
+            let zip = |f: fn(bool, bool) -> bool| -> Vec<bool> {
+                expected
+                    .iter()
+                    .zip(&other_bits)
+                    .map(|(&a, &b)| f(a, b))
+                    .collect()
+            };
+            assert_eq!(bitmap(&set.union(&other_set)), zip(|a, b| a || b));
+            assert_eq!(bitmap(&set.intersection(&other_set)), zip(|a, b| a && b));
+            assert_eq!(bitmap(&set.difference(&other_set)), zip(|a, b| a && !b));
+            assert_eq!(
+                set.is_subset(&other_set),
+                zip(|a, b| !a || b).iter().all(|&b| b)
+            );
+            assert_eq!(
+                set.is_disjoint(&other_set),
+                !zip(|a, b| a && b).iter().any(|&b| b)
+            );
+
+            let gaps = set.gaps(10..50);
+            let gap_bits = bitmap_of(&gaps);
+            for v in 0..UNIVERSE {
+                assert_eq!(gap_bits[v], (10..50).contains(&v) && !expected[v]);
+            }
+            for range in &other {
+                let values = || range.clone().map(|v| expected[v]);
+                assert_eq!(set.contains_range(range), values().all(|b| b));
+                assert_eq!(set.overlaps(range), values().any(|b| b));
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/// Letters of the 4x6 font used by the puzzles that draw their answer (e.g. 2022 day 10),
+/// lit pixels as `#`, row by row.
+const FONT: [(char, [&str; 6]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn in a 6 rows tall image, where each letter is 4 pixels wide and
+/// followed by one blank column. Any char other than `#` is an unlit pixel.
+/// Returns `None` if the image isn't 6 rows tall or a letter isn't recognised.
+///
+/// ```
+/// use advent_of_code::helpers::ocr::read_letters;
+/// let image = [
+///     "#..#.####.",
+///     "#..#....#.",
+///     "####...#..",
+///     "#..#..#...",
+///     "#..#.#....",
+///     "#..#.####.",
+/// ];
+/// assert_eq!(read_letters(&image.join("\n")), Some("HZ".to_string()));
+/// ```
+pub fn read_letters(image: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = image
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    if rows.len() != 6 {
+        return None;
+    }
+    let width = rows.iter().map(Vec::len).max()?;
+    (0..width)
+        .step_by(5)
+        .map(|left| {
+            let lit = |row: &Vec<bool>, x: usize| row.get(left + x).copied().unwrap_or(false);
+            FONT.iter()
+                .find(|(_, glyph)| {
+                    glyph.iter().zip(&rows).all(|(pattern, row)| {
+                        pattern
+                            .chars()
+                            .enumerate()
+                            .all(|(x, pixel)| lit(row, x) == (pixel == '#'))
+                    })
+                })
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_letters() {
+        let image: Vec<String> = (0..6)
+            .map(|row| {
+                FONT.iter()
+                    .map(|(_, glyph)| format!("{}.", glyph[row]))
+                    .collect()
+            })
+            .collect();
+        let letters: String = FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read_letters(&image.join("\n")), Some(letters));
+        assert_eq!(read_letters("##..##..\n##..##.."), None);
+        let garbled = image.join("\n").replacen('.', "#", 1);
+        assert_eq!(read_letters(&garbled), None);
+    }
+}
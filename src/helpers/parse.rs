@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Integer types that can be extracted from text with [`ints`].
+pub trait Integer: FromStr + Copy {
+    /// Whether a `-` right before the digits belongs to the number.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr, $($t:ty),*) => {
+        $(impl Integer for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_integer!(true, i8, i16, i32, i64, i128, isize);
+impl_integer!(false, u8, u16, u32, u64, u128, usize);
+
+/// Iterator over the integers of a string, see [`ints`].
+pub struct Ints<'a, T> {
+    rest: &'a str,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T: Integer> Iterator for Ints<'a, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Result<T, ParseError>> {
+        let bytes = self.rest.as_bytes();
+        let mut start = bytes.iter().position(u8::is_ascii_digit)?;
+        let len = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(bytes.len() - start);
+        let end = start + len;
+        if T::SIGNED && start > 0 && bytes[start - 1] == b'-' {
+            start -= 1;
+        }
+        let number = &self.rest[start..end];
+        self.rest = &self.rest[end..];
+        Some(number.parse().map_err(|_| ParseError {
+            expected: std::any::type_name::<T>().to_string(),
+            remaining: number.to_string(),
+        }))
+    }
+}
+
+/// Extracts every integer of `line` in order, ignoring whatever separates them.
+/// A `-` directly before digits makes the number negative when `T` is signed.
+/// Nothing is allocated: numbers are parsed straight from slices of `line`.
+/// A number that doesn't fit in `T` is an error, and extraction goes on after it.
+///
+/// ```
+/// use advent_of_code::helpers::parse::ints;
+/// let numbers: Result<Vec<i64>, _> = ints("Sensor at x=2, y=-18").collect();
+/// assert_eq!(numbers, Ok(vec![2, -18]));
+/// ```
+pub fn ints<T: Integer>(line: &str) -> Ints<'_, T> {
+    Ints {
+        rest: line,
+        marker: PhantomData,
+    }
+}
+
+/// Extracts exactly `N` integers from `line`, or `None` if it holds more or fewer, or one of
+/// them doesn't fit in `T`.
+///
+/// ```
+/// use advent_of_code::helpers::parse::ints_n;
+/// let [amount, from, to] = ints_n::<usize, 3>("move 1 from 2 to 1").unwrap();
+/// assert_eq!((amount, from, to), (1, 2, 1));
+/// ```
+pub fn ints_n<T: Integer + Default, const N: usize>(line: &str) -> Option<[T; N]> {
+    let mut values = [T::default(); N];
+    let mut found = ints(line);
+    for value in values.iter_mut() {
+        *value = found.next()?.ok()?;
+    }
+    match found.next() {
+        Some(_) => None,
+        None => Some(values),
+    }
+}
+
+/// What a parser expected and the input left when it failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: String,
+    pub remaining: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {} at {:?}", self.expected, self.remaining)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The parsed value and the input left after it.
+pub type ParseResult<'a, T> = Result<(T, &'a str), ParseError>;
+
+fn fail<T>(expected: impl Into<String>, remaining: &str) -> ParseResult<'_, T> {
+    Err(ParseError {
+        expected: expected.into(),
+        remaining: remaining.to_string(),
+    })
+}
+
+/// Matches `expected` literally.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(&'a str) -> ParseResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => fail(format!("{:?}", expected), input),
+    }
+}
+
+/// Consumes one or more chars matching `predicate`.
+pub fn take_while1<'a>(
+    description: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Fn(&'a str) -> ParseResult<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c: char| !predicate(c)).unwrap_or(input.len());
+        match end {
+            0 => fail(description, input),
+            _ => Ok((&input[..end], &input[end..])),
+        }
+    }
+}
+
+/// A run of letters, digits or underscores.
+pub fn word<'a>() -> impl Fn(&'a str) -> ParseResult<'a, &'a str> {
+    take_while1("a word", |c| c.is_alphanumeric() || c == '_')
+}
+
+/// Optional whitespace.
+pub fn ws(input: &str) -> ParseResult<'_, ()> {
+    Ok(((), input.trim_start()))
+}
+
+/// An integer, with an optional leading `-` when `T` is signed.
+pub fn int<'a, T: Integer>() -> impl Fn(&'a str) -> ParseResult<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(T::SIGNED && input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+        if digits == 0 {
+            return fail("an integer", input);
+        }
+        let end = sign + digits;
+        match input[..end].parse() {
+            Ok(value) => Ok((value, &input[end..])),
+            Err(_) => fail(std::any::type_name::<T>(), input),
+        }
+    }
+}
+
+pub fn map<'a, A, B>(
+    parser: impl Fn(&'a str) -> ParseResult<'a, A>,
+    f: impl Fn(A) -> B,
+) -> impl Fn(&'a str) -> ParseResult<'a, B> {
+    move |input| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Fn(&'a str) -> ParseResult<'a, A>,
+    second: impl Fn(&'a str) -> ParseResult<'a, B>,
+) -> impl Fn(&'a str) -> ParseResult<'a, (A, B)> {
+    move |input| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Runs both parsers, keeping the value of the second one.
+pub fn preceded<'a, A, B>(
+    first: impl Fn(&'a str) -> ParseResult<'a, A>,
+    second: impl Fn(&'a str) -> ParseResult<'a, B>,
+) -> impl Fn(&'a str) -> ParseResult<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// Runs both parsers, keeping the value of the first one.
+pub fn terminated<'a, A, B>(
+    first: impl Fn(&'a str) -> ParseResult<'a, A>,
+    second: impl Fn(&'a str) -> ParseResult<'a, B>,
+) -> impl Fn(&'a str) -> ParseResult<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// Tries `first`, then `second` on the same input if it failed.
+pub fn either<'a, T>(
+    first: impl Fn(&'a str) -> ParseResult<'a, T>,
+    second: impl Fn(&'a str) -> ParseResult<'a, T>,
+) -> impl Fn(&'a str) -> ParseResult<'a, T> {
+    move |input| first(input).or_else(|_| second(input))
+}
+
+/// One or more `item`s separated by `separator`.
+pub fn separated1<'a, T, S>(
+    item: impl Fn(&'a str) -> ParseResult<'a, T>,
+    separator: impl Fn(&'a str) -> ParseResult<'a, S>,
+) -> impl Fn(&'a str) -> ParseResult<'a, Vec<T>> {
+    move |input| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after_separator)) = separator(rest) {
+            let (next, after_item) = item(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Applies `parser` to the whole of `input` (surrounding whitespace aside),
+/// failing if anything is left over.
+pub fn parse_all<'a, T>(
+    parser: impl Fn(&'a str) -> ParseResult<'a, T>,
+    input: &'a str,
+) -> Result<T, ParseError> {
+    let (value, rest) = parser(input.trim())?;
+    match rest {
+        "" => Ok(value),
+        _ => Err(ParseError {
+            expected: "end of input".to_string(),
+            remaining: rest.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let signed: Result<Vec<i32>, _> = ints("x=-3, y=4 -> 10-2").collect();
+        assert_eq!(signed, Ok(vec![-3, 4, 10, -2]));
+        let unsigned: Result<Vec<u32>, _> = ints("x=-3, y=4").collect();
+        assert_eq!(unsigned, Ok(vec![3, 4]));
+        assert_eq!(ints_n::<u8, 2>("1-2"), Some([1, 2]));
+        assert_eq!(ints_n::<u8, 2>("1-2-3"), None);
+        assert_eq!(ints_n::<u8, 2>("1"), None);
+    }
+
+    #[test]
+    fn test_ints_out_of_range() {
+        let mut found = ints::<u8>("id 7 of 99999999999999999999 and 300, then 12");
+        assert_eq!(found.next(), Some(Ok(7)));
+        let error = found.next().unwrap().unwrap_err();
+        assert_eq!(error.expected, "u8");
+        assert_eq!(error.remaining, "99999999999999999999");
+        assert!(found.next().unwrap().is_err());
+        assert_eq!(found.next(), Some(Ok(12)));
+        assert_eq!(found.next(), None);
+        assert_eq!(ints_n::<u8, 2>("1 300"), None);
+    }
+
+    #[test]
+    fn test_combinators() {
+        let list = preceded(tag("Starting items: "), separated1(int::<u64>(), tag(", ")));
+        assert_eq!(
+            parse_all(&list, "  Starting items: 79, 98\n"),
+            Ok(vec![79, 98])
+        );
+
+        let operand = either(map(tag("old"), |_| None), map(int::<u64>(), Some));
+        let operation = pair(
+            terminated(take_while1("an operator", |c| c == '+' || c == '*'), ws),
+            operand,
+        );
+        assert_eq!(parse_all(&operation, "* old"), Ok(("*", None)));
+        assert_eq!(parse_all(&operation, "+ 6"), Ok(("+", Some(6))));
+
+        let error = parse_all(&operation, "/ 6").unwrap_err();
+        assert_eq!(error.expected, "an operator");
+        assert_eq!(error.to_string(), "expected an operator at \"/ 6\"");
+        assert!(parse_all(&list, "Starting items: 1, x").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Integer types usable as point coordinates.
+pub trait Coordinate:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A point (or a vector, see [`Vec2`]) on a 2D plane where `y` grows downwards,
+/// like rows of the puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Displacement between two points.
+pub type Vec2<T> = Point2<T>;
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const ORIGIN: Point2<T> = Point2::new(T::ZERO, T::ZERO);
+
+    pub fn manhattan_distance(&self, other: &Point2<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of king moves between two points, diagonals counting as a single step.
+    pub fn chebyshev_distance(&self, other: &Point2<T>) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Clamps each component to -1, 0 or 1: a unit step in the direction of the vector.
+    pub fn signum(&self) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        let signum = |v: T| match v.cmp(&T::ZERO) {
+            std::cmp::Ordering::Less => -T::ONE,
+            std::cmp::Ordering::Equal => T::ZERO,
+            std::cmp::Ordering::Greater => T::ONE,
+        };
+        Point2::new(signum(self.x), signum(self.y))
+    }
+
+    /// Quarter turn clockwise around the origin (with `y` pointing down).
+    pub fn rotate_cw(&self) -> Point2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Quarter turn counter-clockwise around the origin (with `y` pointing down).
+    pub fn rotate_ccw(&self) -> Point2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, factor: T) -> Point2<T> {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The 4 orthogonal directions, `Up` meaning towards smaller `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Vec2<T> {
+        Direction8::from(self).offset()
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+}
+
+/// The 8 king-move directions, clockwise starting from `Up`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Vec2<T> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        match self {
+            Direction8::Up => Point2::new(zero, -one),
+            Direction8::UpRight => Point2::new(one, -one),
+            Direction8::Right => Point2::new(one, zero),
+            Direction8::DownRight => Point2::new(one, one),
+            Direction8::Down => Point2::new(zero, one),
+            Direction8::DownLeft => Point2::new(-one, one),
+            Direction8::Left => Point2::new(-one, zero),
+            Direction8::UpLeft => Point2::new(-one, -one),
+        }
+    }
+
+    /// The direction pointing the same way as `vector`, if it is not null.
+    pub fn from_offset<T: Coordinate + Neg<Output = T>>(vector: Vec2<T>) -> Option<Direction8> {
+        let unit = vector.signum();
+        Direction8::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == unit)
+    }
+
+    /// Eighth of a turn clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth of a turn counter-clockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Smallest axis-aligned rectangle containing a set of points, bounds included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coordinate> BoundingBox<T> {
+    pub fn from_points<'a>(
+        points: impl IntoIterator<Item = &'a Point2<T>>,
+    ) -> Option<BoundingBox<T>>
+    where
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bounds = BoundingBox {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.include(point));
+        Some(bounds)
+    }
+
+    /// Grows the box so that it contains `point`.
+    pub fn include(&mut self, point: &Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: &Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 2, Point2::new(6, -4));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1_usize, 5);
+        let b = Point2::new(4_usize, 1);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.offset::<i32>(), Point2::new(0, -1));
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(
+            Direction8::from_offset(Point2::new(5, -3)),
+            Some(Direction8::UpRight)
+        );
+        assert_eq!(Direction8::from_offset(Point2::new(0, 0)), None);
+        assert_eq!(
+            Direction::Right.offset::<i32>().rotate_cw(),
+            Direction::Down.offset()
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(2, 3), Point2::new(-1, 7), Point2::new(4, 0)];
+        let bounds = BoundingBox::from_points(&points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-1, 0));
+        assert_eq!(bounds.max, Point2::new(4, 7));
+        assert_eq!((bounds.width(), bounds.height()), (6, 8));
+        assert!(bounds.contains(&Point2::new(0, 0)));
+        assert!(!bounds.contains(&Point2::new(5, 0)));
+    }
+}
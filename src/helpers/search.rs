@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Distances and shortest-path tree produced by one of the searches below.
+/// Only nodes reached before the search stopped are present.
+#[derive(Clone, Debug)]
+pub struct SearchResult<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    target: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The first node accepted by the `is_target` predicate, if any was reached.
+    pub fn target(&self) -> Option<&N> {
+        self.target.as_ref()
+    }
+
+    /// Distance from the closest source to the target.
+    pub fn target_distance(&self) -> Option<C> {
+        self.distance(self.target()?)
+    }
+
+    /// Every reached node along with its distance from the closest source.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.distances.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    /// Nodes from a source to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn target_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.target()?)
+    }
+}
+
+/// Breadth-first search on an unweighted graph, every edge costing 1.
+/// Explores from all `sources` at once and stops at the first node matching `is_target`
+/// (pass `|_| false` to explore everything reachable).
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_target: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        target: None,
+    };
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if result.distances.insert(source.clone(), 0).is_none() {
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_target(&node) {
+            result.target = Some(node);
+            break;
+        }
+        let distance = result.distances[&node] + 1;
+        for neighbour in neighbours(&node) {
+            if !result.distances.contains_key(&neighbour) {
+                result.distances.insert(neighbour.clone(), distance);
+                result.parents.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's shortest paths on a weighted graph: `neighbours` yields `(node, edge cost)`.
+/// Same multi-source and early-exit behaviour as [`bfs`].
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_target: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(sources, neighbours, |_| C::default(), is_target)
+}
+
+/// A* search: Dijkstra guided by `heuristic`, an estimate of the remaining cost to a target.
+/// The heuristic must never overestimate for the result to be a shortest path.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_target: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        target: None,
+    };
+    let mut heap = BinaryHeap::new();
+    for source in sources {
+        if result
+            .distances
+            .insert(source.clone(), C::default())
+            .is_none()
+        {
+            heap.push(Frontier {
+                priority: heuristic(&source),
+                cost: C::default(),
+                node: source,
+            });
+        }
+    }
+
+    while let Some(Frontier { cost, node, .. }) = heap.pop() {
+        // stale entry: a shorter path to this node was found after it was pushed
+        if result.distances[&node] < cost {
+            continue;
+        }
+        if is_target(&node) {
+            result.target = Some(node);
+            break;
+        }
+        for (neighbour, edge_cost) in neighbours(&node) {
+            let next_cost = cost + edge_cost;
+            let improved = match result.distances.get(&neighbour) {
+                Some(&known) => next_cost < known,
+                None => true,
+            };
+            if improved {
+                result.distances.insert(neighbour.clone(), next_cost);
+                result.parents.insert(neighbour.clone(), node.clone());
+                heap.push(Frontier {
+                    priority: next_cost + heuristic(&neighbour),
+                    cost: next_cost,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+    result
+}
+
+/// Heap entry ordered so that `BinaryHeap` pops the lowest priority first.
+struct Frontier<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Frontier<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Frontier<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Frontier<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Frontier<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // prefer deeper nodes on ties, they are closer to the target when a heuristic is used
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1- b -1- c
+    //  \         /
+    //   ----5----
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('a', 5), ('b', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(['a'], unweighted, |&n| n == 'c');
+        assert_eq!(result.target_distance(), Some(1));
+        assert_eq!(result.target_path(), Some(vec!['a', 'c']));
+
+        let result = bfs(['a'], unweighted, |_| false);
+        assert_eq!(result.distances().count(), 3);
+        assert_eq!(result.target(), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra(['a'], weighted, |&n| n == 'c');
+        assert_eq!(result.target_distance(), Some(2));
+        assert_eq!(result.target_path(), Some(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let result = dijkstra(['a', 'c'], weighted, |_| false);
+        assert_eq!(result.distance(&'b'), Some(1));
+        assert_eq!(result.distance(&'c'), Some(0));
+    }
+
+    #[test]
+    fn test_astar_on_implicit_grid() {
+        // 10x10 open grid, walking from the top-left to the bottom-right corner
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .map(|n| (n, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x) + (9 - y);
+        let result = astar([(0, 0)], neighbours, heuristic, |&n| n == (9, 9));
+        assert_eq!(result.target_distance(), Some(18));
+        assert_eq!(result.target_path().map(|path| path.len()), Some(19));
+        // the heuristic is exact here, so barely more than the optimal path gets expanded
+        assert!(result.distances().count() < 100);
+    }
+}
@@ -0,0 +1,239 @@
+/*
+ * Every real input of a day, to catch the bugs that only show on some of them: the default
+ * `src/inputs/NN.txt`, whose answers are recorded in `src/answers/NN.txt`, and one
+ * `src/inputs/NN/<name>.txt` per team member, with answers in `src/inputs/NN/<name>.answers`.
+ * Answer files are optional and use the format of `answer::parse_recorded`. With the
+ * `embed-inputs` feature, all of them are compiled into the binary like `read_file`'s.
+ */
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::answer::read_recorded;
+use crate::{Answer, ANSI_BOLD, ANSI_RESET};
+
+/// Name of the input in `src/inputs/NN.txt`.
+pub const DEFAULT: &str = "default";
+
+#[derive(Clone, Debug)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    /// Known answers to part 1 and part 2.
+    pub expected: [Option<Answer>; 2],
+}
+
+/// A part whose answer differs from the expected one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub input: String,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Option<Answer>,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "input `{}` fails part {}: expected {}, got ",
+            self.input, self.part, self.expected
+        )?;
+        match &self.actual {
+            Some(actual) => write!(f, "{}", actual),
+            None => write!(f, "nothing"),
+        }
+    }
+}
+
+fn directory(day: u8) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap();
+    cwd.join("src").join("inputs").join(format!("{:02}", day))
+}
+
+/// Every input of `day`, the default one first and the others by name. With the
+/// `embed-inputs` feature, an input embedded at build time wins over the file on disk, even
+/// if the file changed since, like with `read_file`. Inputs added since the build are still
+/// read from disk.
+pub fn all(day: u8) -> Vec<Input> {
+    let mut inputs = Vec::new();
+    if let Some(text) = crate::try_read_file("inputs", day) {
+        inputs.push(Input {
+            name: DEFAULT.to_string(),
+            text,
+            expected: crate::answer::recorded(day),
+        });
+    }
+
+    let mut members = BTreeMap::new();
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory(day))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    for path in paths {
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let (Some(name), Ok(text)) = (path.file_stem(), fs::read_to_string(&path)) else {
+            continue;
+        };
+        let expected = read_recorded(&path.with_extension("answers"));
+        members.insert(name.to_string_lossy().to_string(), (text, expected));
+    }
+    // replacing the ones read from disk
+    #[cfg(feature = "embed-inputs")]
+    for (name, text, answers) in crate::embedded::members(day) {
+        let expected = crate::answer::parse_recorded(answers.unwrap_or_default());
+        members.insert(name.to_string(), (text.to_string(), expected));
+    }
+
+    inputs.extend(members.into_iter().map(|(name, (text, expected))| Input {
+        name,
+        text,
+        expected,
+    }));
+    inputs
+}
+
+impl Input {
+    /// Whether the input has answers to check against.
+    pub fn has_expected(&self) -> bool {
+        self.expected.iter().any(Option::is_some)
+    }
+
+    /// The parts whose answer differs from the expected one. Answers are compared in their
+    /// short form, so that a drawn answer matches the letters recorded for it.
+    pub fn check(&self, answers: [Option<Answer>; 2]) -> Vec<Failure> {
+        self.expected
+            .iter()
+            .zip(answers)
+            .zip(1..)
+            .filter_map(|((expected, actual), part)| {
+                let expected = expected.as_ref()?;
+                let matches = actual
+                    .as_ref()
+                    .is_some_and(|actual| actual.short() == expected.short());
+                (!matches).then(|| Failure {
+                    input: self.name.clone(),
+                    part,
+                    expected: expected.clone(),
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Solves both parts on every input like `solve!`, with a header per input when there are
+/// several. Returns the parts whose answer differs from the expected one, and the time spent
+/// solving.
+pub fn solve_inputs<A: Into<Answer>, B: Into<Answer>>(
+    inputs: &[Input],
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) -> (Vec<Failure>, Duration) {
+    let mut failures = Vec::new();
+    let mut elapsed = Duration::ZERO;
+    for input in inputs {
+        if inputs.len() > 1 {
+            println!("{}── {} ──{}", ANSI_BOLD, input.name, ANSI_RESET);
+        }
+        let (answer1, elapsed1) = crate::run_part_timed(1, &part_one, &input.text);
+        let (answer2, elapsed2) = crate::run_part_timed(2, &part_two, &input.text);
+        elapsed += elapsed1 + elapsed2;
+        failures.extend(input.check([answer1, answer2]));
+    }
+    (failures, elapsed)
+}
+
+/// Solves both parts on every input of `day` with `solve_inputs`, then exits with an error if
+/// any answer differs from the expected one.
+pub fn solve_all<A: Into<Answer>, B: Into<Answer>>(
+    day: u8,
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) {
+    let inputs = all(day);
+    if inputs.is_empty() {
+        eprintln!(
+            "No input for day {}: add src/inputs/{:02}.txt or src/inputs/{:02}/<name>.txt",
+            day, day, day
+        );
+        std::process::exit(1);
+    }
+
+    let (failures, _) = solve_inputs(&inputs, part_one, part_two);
+    if !failures.is_empty() {
+        for failure in &failures {
+            eprintln!("✗ day {:02}: {}", day, failure);
+        }
+        std::process::exit(1);
+    }
+}
+
+/// Checks both parts against every input of `day` that has expected answers, panicking with
+/// the list of inputs and parts that fail. Meant for tests.
+pub fn assert_all<A: Into<Answer>, B: Into<Answer>>(
+    day: u8,
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) {
+    let failures: Vec<String> = all(day)
+        .iter()
+        .filter(|input| input.has_expected())
+        .flat_map(|input| {
+            let answer = |part: u8| match (part, &input.expected[part as usize - 1]) {
+                (_, None) => None,
+                (1, _) => part_one(&input.text).map(Into::into),
+                _ => part_two(&input.text).map(Into::into),
+            };
+            input.check([answer(1), answer(2)])
+        })
+        .map(|failure| failure.to_string())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "day {:02}:\n{}",
+        day,
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let input = Input {
+            name: "alice".to_string(),
+            text: String::new(),
+            expected: [Some(Answer::from(24000)), Some(Answer::from("EH"))],
+        };
+        let image = "\
+####.#..#
+#....#..#
+###..####
+#....#..#
+#....#..#
+####.#..#";
+        assert_eq!(
+            input.check([Some(Answer::from(24000)), Some(Answer::rendered(image))]),
+            vec![]
+        );
+        let failures = input.check([Some(Answer::from(23000)), None]);
+        assert_eq!(
+            failures.iter().map(Failure::to_string).collect::<Vec<_>>(),
+            vec![
+                "input `alice` fails part 1: expected 24000, got 23000",
+                "input `alice` fails part 2: expected EH, got nothing",
+            ]
+        );
+    }
+}
@@ -5,49 +5,138 @@
  */
 use std::env;
 use std::fs;
-
+use std::time::{Duration, Instant};
+
+pub mod answer;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod generators;
 pub mod helpers;
+pub mod inputs;
+pub mod registry;
+pub mod snapshot;
+pub mod visualize;
+
+pub use answer::Answer;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs and times one part, then prints its answer. Returns the answer, see `solve!`.
+pub fn run_part<T: Into<Answer>>(
+    part: u8,
+    solver: impl FnOnce(&str) -> Option<T>,
+    input: &str,
+) -> Option<Answer> {
+    run_part_timed(part, solver, input).0
+}
+
+/// Like `run_part`, also returning the time the part took.
+pub fn run_part_timed<T: Into<Answer>>(
+    part: u8,
+    solver: impl FnOnce(&str) -> Option<T>,
+    input: &str,
+) -> (Option<Answer>, Duration) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    let timer = Instant::now();
+    let result = solver(input);
+    let elapsed = timer.elapsed();
+    let answer = result.map(Into::into);
+    match &answer {
+        Some(answer) => {
+            // drawn answers are printed in full, followed by the letters read from them
+            if let Some(image) = answer.image() {
+                println!("{}", image);
+            }
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer.short().unwrap_or_else(|| "(unreadable)".to_string()),
+                ANSI_ITALIC,
+                elapsed,
+                ANSI_RESET
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
+    (answer, elapsed)
+}
+
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+        $crate::run_part($part, $solver, $input)
+    }};
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+/// Compares a rendered `String` with the snapshot `src/snapshots/NN-<name>.txt`,
+/// panicking with a diff if they differ. See `advent_of_code::snapshot`.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($day:expr, $name:expr, $actual:expr) => {{
+        if let Err(message) = $crate::snapshot::check($day, $name, &$actual) {
+            panic!("{}", message);
+        }
     }};
 }
 
+/// Inputs, examples and answers embedded at compile time, generated by `build.rs`.
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+    pub fn get(folder: &str, day: u8) -> Option<&'static str> {
+        FILES
+            .iter()
+            .find(|(f, d, _)| *f == folder && *d == day)
+            .map(|(_, _, contents)| *contents)
+    }
+
+    /// The name, input and answers of every team member's input for `day`, by name.
+    pub fn members(
+        day: u8,
+    ) -> impl Iterator<Item = (&'static str, &'static str, Option<&'static str>)> {
+        MEMBERS
+            .iter()
+            .filter(move |(d, ..)| *d == day)
+            .map(|(_, name, text, answers)| (*name, *text, *answers))
+    }
+}
+
+/// Reads `src/<folder>/NN.txt`. With the `embed-inputs` feature, the files that existed at
+/// build time are compiled into the binary and the others are still read from disk. Team
+/// members' inputs are embedded too, see `inputs::all`.
 pub fn read_file(folder: &str, day: u8) -> String {
+    try_read_file(folder, day).expect("could not open input file")
+}
+
+/// Like `read_file`, `None` if the file doesn't exist.
+pub fn try_read_file(folder: &str, day: u8) -> Option<String> {
+    #[cfg(feature = "embed-inputs")]
+    if let Some(contents) = embedded::get(folder, day) {
+        return Some(contents.to_string());
+    }
+
     let cwd = env::current_dir().unwrap();
 
     let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    fs::read_to_string(filepath).ok()
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
@@ -77,6 +166,22 @@ pub fn parse_exec_time(output: &str) -> f64 {
     })
 }
 
+/// The answers printed by `solve!`, in order, `None` for the parts that aren't solved.
+pub fn parse_answers(output: &str) -> Vec<Option<Answer>> {
+    output
+        .lines()
+        .filter_map(|l| {
+            if l.contains("not solved.") {
+                Some(None)
+            } else {
+                let (answer, _) = l.split_once("(elapsed: ")?;
+                let answer = answer.trim_end().trim_end_matches(ANSI_ITALIC).trim_end();
+                Some(answer.parse().ok())
+            }
+        })
+        .collect()
+}
+
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
 #[cfg(test)]
 macro_rules! assert_approx_eq {
@@ -95,6 +200,28 @@ macro_rules! assert_approx_eq {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_answers() {
+        let output = format!(
+            "🎄 Part 1 🎄\n157 {}(elapsed: 1.00µs){}\n🎄 Part 2 🎄\nnot solved.\n",
+            ANSI_ITALIC, ANSI_RESET
+        );
+        assert_eq!(parse_answers(&output), vec![Some(Answer::from(157)), None]);
+        assert_eq!(
+            parse_answers("🎄 Part 1 🎄\n#..#\nCMZ (elapsed: 2ms)"),
+            vec![Some(Answer::from("CMZ"))]
+        );
+    }
+
+    #[cfg(feature = "embed-inputs")]
+    #[test]
+    fn test_read_embedded_file() {
+        let path = env::current_dir().unwrap().join("src/examples/01.txt");
+        let expected = fs::read_to_string(path).unwrap();
+        assert_eq!(embedded::get("examples", 1), Some(expected.as_str()));
+        assert_eq!(embedded::get("examples", 26), None);
+    }
+
     #[test]
     fn test_parse_exec_time() {
         assert_approx_eq!(
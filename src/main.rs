@@ -2,45 +2,48 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+use advent_of_code::registry::{self, Solution};
+use advent_of_code::{inputs, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+/// Solves every input of the day, returning the time spent in its parts.
+fn run_day(solution: &Solution) -> Duration {
+    let inputs = inputs::all(solution.day);
+    if inputs.is_empty() {
+        println!("No input.");
+        return Duration::ZERO;
+    }
+    let (failures, elapsed) = inputs::solve_inputs(&inputs, solution.part_one, solution.part_two);
+    for failure in &failures {
+        eprintln!("✗ day {:02}: {}", solution.day, failure);
+    }
+    elapsed
+}
 
+fn main() {
+    let total: Duration = (1..=25)
+        .map(|day| {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+            match registry::solution(day) {
+                Some(solution) => run_day(solution),
+                None => {
+                    println!("Not solved.");
+                    Duration::ZERO
                 }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
             }
         })
         .sum();
 
     println!(
         "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
     );
 }
@@ -0,0 +1,79 @@
+/*
+ * Every solved day, with both parts returning an `Answer` whatever type the day's own parts
+ * return. Shared by the runner (`cargo all`), `cargo status` and the benchmarks, so that none
+ * of them keeps its own list of days. `cargo scaffold` adds new days here.
+ */
+use crate::Answer;
+
+/// A part of a day, its answer converted to an `Answer`.
+pub type Part = fn(&str) -> Option<Answer>;
+
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: u8,
+    pub part_one: Part,
+    pub part_two: Part,
+}
+
+impl Solution {
+    /// Part 1 or part 2.
+    pub fn part(&self, part: u8) -> Part {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => panic!("no part {}", part),
+        }
+    }
+}
+
+macro_rules! solution {
+    ($day:expr, $module:ident) => {
+        Solution {
+            day: $day,
+            part_one: |input| crate::$module::part_one(input).map(Into::into),
+            part_two: |input| crate::$module::part_two(input).map(Into::into),
+        }
+    };
+}
+
+/// The solved days, in order.
+pub static SOLUTIONS: &[Solution] = &[
+    solution!(1, day01),
+    solution!(2, day02),
+    solution!(3, day03),
+    solution!(4, day04),
+    solution!(5, day05),
+    solution!(6, day06),
+    solution!(7, day07),
+    solution!(8, day08),
+    solution!(9, day09),
+    solution!(10, day10),
+    solution!(11, day11),
+    solution!(12, day12),
+    solution!(13, day13),
+    solution!(14, day14),
+    solution!(15, day15),
+];
+
+/// The solution of `day`, if it is solved.
+pub fn solution(day: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|solution| solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions() {
+        let days: Vec<u8> = SOLUTIONS.iter().map(|solution| solution.day).collect();
+        assert_eq!(days, (1..=15).collect::<Vec<u8>>());
+
+        let input = crate::read_file("examples", 5);
+        let day05 = solution(5).unwrap();
+        assert_eq!((day05.part(1))(&input), Some(Answer::from("CMZ")));
+        assert_eq!((day05.part_two)(&input), Some(Answer::from("MCD")));
+        assert_eq!((solution(1).unwrap().part_one)("1000\n\n2000"), Some(Answer::from(2000)));
+        assert!(solution(25).is_none());
+    }
+}
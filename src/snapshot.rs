@@ -0,0 +1,95 @@
+/*
+ * Snapshot testing for rendered outputs (images, grids, ...), see `assert_snapshot!`.
+ * Snapshots live in `src/snapshots/NN-<name>.txt`. Run the tests with `UPDATE_SNAPSHOTS=1`
+ * to create missing snapshots or overwrite the ones that changed, then review them with git.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const UPDATE_VAR: &str = "UPDATE_SNAPSHOTS";
+
+pub fn snapshot_path(day: u8, name: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("src")
+        .join("snapshots")
+        .join(format!("{:02}-{}.txt", day, name))
+}
+
+/// Compares `actual` with the stored snapshot, ignoring trailing newlines, or stores it when
+/// `UPDATE_SNAPSHOTS` is set. The error describes the mismatch with a line diff.
+pub fn check(day: u8, name: &str, actual: &str) -> Result<(), String> {
+    let path = snapshot_path(day, name);
+    let actual = actual.trim_end_matches('\n');
+
+    if env::var_os(UPDATE_VAR).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        return fs::write(&path, format!("{}\n", actual))
+            .map_err(|e| format!("could not write snapshot {}: {}", path.display(), e));
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected.replace("\r\n", "\n"),
+        Err(_) => {
+            return Err(format!(
+                "no snapshot at {}, run with {}=1 to create it. got:\n{}",
+                path.display(),
+                UPDATE_VAR,
+                actual
+            ))
+        }
+    };
+    let expected = expected.trim_end_matches('\n');
+    match expected == actual {
+        true => Ok(()),
+        false => Err(format!(
+            "snapshot {} doesn't match (- snapshot, + actual), run with {}=1 to accept the changes:\n{}",
+            path.display(),
+            UPDATE_VAR,
+            diff(expected, actual)
+        )),
+    }
+}
+
+/// Line diff of two texts: `- ` for lines only in `expected`, `+ ` for lines only in `actual`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    // longest common subsequence lengths of every pair of suffixes
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\nc", "a\nb\nc"), "  a\n  b\n  c");
+        assert_eq!(diff("a\nb\nc", "a\nx\nc\nd"), "  a\n- b\n+ x\n  c\n+ d");
+        assert_eq!(diff("a\nb", ""), "- a\n- b");
+    }
+}
@@ -0,0 +1,196 @@
+/*
+ * Watch simulations frame by frame: a day implements `Visualize` to describe its frames, and
+ * `Visualization::from_args` lets `cargo solve NN -- <flags>` play them in the terminal or
+ * export them instead of solving:
+ *
+ *     --play              play in the terminal (space: pause, n/b: step, +/-: speed, q: quit)
+ *     --text <file>       write every frame to a text file
+ *     --ppm <directory>   write every frame as a PPM image
+ *     --gif <file>        write an animated GIF
+ *     --fps <n>           frames per second (default 10)
+ *     --every <n>         keep one frame out of n (default 1)
+ *     --scale <n>         pixels per cell in images (default 4)
+ *     --part <n>          which part to visualize, for days that have several (default 1)
+ */
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use crate::helpers::Grid;
+
+pub mod export;
+pub mod player;
+
+/// One state of a simulation: a grid of chars and a line describing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<char>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, cells: Grid<char>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            cells,
+        }
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(f, "{}", self.cells)
+    }
+}
+
+/// Colour of a char in exported images.
+pub type Palette = fn(char) -> [u8; 3];
+
+/// A simulation that can be watched frame by frame.
+pub trait Visualize {
+    /// The successive states of the simulation. Frames are produced lazily, as long
+    /// simulations can have tens of thousands of them, and should all have the same size.
+    fn frames(self) -> Box<dyn Iterator<Item = Frame>>;
+
+    /// Colours used when exporting images.
+    fn palette(&self) -> Palette {
+        default_palette
+    }
+}
+
+/// Black background, white walls, a green to white gradient for heights `a` to `z`, the same
+/// gradient tinted blue for `A` to `Z`, and warm colours for moving things.
+pub fn default_palette(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [16, 16, 32],
+        '#' => [220, 220, 220],
+        'o' | '~' => [230, 190, 90],
+        '0'..='9' | 'H' | 'T' => [255, 140, 40],
+        '>' | '<' | '^' | 'v' | '*' | '@' => [255, 60, 60],
+        'a'..='z' => {
+            let level = (c as u8 - b'a') as u32;
+            [(level * 8) as u8, (60 + level * 7) as u8, (level * 8) as u8]
+        }
+        'A'..='Z' => {
+            let level = (c as u8 - b'A') as u32;
+            [
+                (level * 6) as u8,
+                (level * 6) as u8,
+                (120 + level * 5) as u8,
+            ]
+        }
+        _ => [255, 0, 255],
+    }
+}
+
+/// What to do with the frames.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    Play,
+    Text(PathBuf),
+    Ppm(PathBuf),
+    Gif(PathBuf),
+}
+
+/// An output and its options, as requested on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Visualization {
+    pub output: Output,
+    pub fps: u32,
+    pub every: usize,
+    pub scale: usize,
+    pub part: u8,
+}
+
+impl Visualization {
+    pub fn new(output: Output) -> Visualization {
+        Visualization {
+            output,
+            fps: 10,
+            every: 1,
+            scale: 4,
+            part: 1,
+        }
+    }
+
+    /// The visualization requested by the flags the binary was started with, if any.
+    /// Exits with a message on invalid flags.
+    pub fn from_args() -> Option<Visualization> {
+        let mut args = pico_args::Arguments::from_env();
+        match Visualization::parse(&mut args) {
+            Ok(visualization) => visualization,
+            Err(e) => {
+                eprintln!("Failed to process visualization arguments: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn parse(args: &mut pico_args::Arguments) -> Result<Option<Visualization>, pico_args::Error> {
+        let output = if args.contains("--play") {
+            Output::Play
+        } else if let Some(path) = args.opt_value_from_str("--text")? {
+            Output::Text(path)
+        } else if let Some(path) = args.opt_value_from_str("--ppm")? {
+            Output::Ppm(path)
+        } else if let Some(path) = args.opt_value_from_str("--gif")? {
+            Output::Gif(path)
+        } else {
+            return Ok(None);
+        };
+        let defaults = Visualization::new(output);
+        Ok(Some(Visualization {
+            fps: args
+                .opt_value_from_str("--fps")?
+                .unwrap_or(defaults.fps)
+                .max(1),
+            every: args
+                .opt_value_from_str("--every")?
+                .unwrap_or(defaults.every)
+                .max(1),
+            scale: args
+                .opt_value_from_str("--scale")?
+                .unwrap_or(defaults.scale)
+                .max(1),
+            part: args.opt_value_from_str("--part")?.unwrap_or(defaults.part),
+            ..defaults
+        }))
+    }
+
+    /// Plays or exports the frames of `simulation`.
+    pub fn run(&self, simulation: impl Visualize) -> std::io::Result<()> {
+        let palette = simulation.palette();
+        let frames = simulation.frames().step_by(self.every);
+        match &self.output {
+            Output::Play => player::play(frames, self.fps),
+            Output::Text(path) => export::text(frames, path),
+            Output::Ppm(directory) => export::ppm(frames, directory, self.scale, palette),
+            Output::Gif(path) => export::gif(frames, path, self.scale, self.fps, palette),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn parse(args: &[&str]) -> Option<Visualization> {
+        let args = args.iter().map(OsString::from).collect();
+        Visualization::parse(&mut pico_args::Arguments::from_vec(args)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]), None);
+        assert_eq!(parse(&["--play"]), Some(Visualization::new(Output::Play)));
+        assert_eq!(
+            parse(&["--gif", "sand.gif", "--every", "5", "--part", "2"]),
+            Some(Visualization {
+                every: 5,
+                part: 2,
+                ..Visualization::new(Output::Gif(PathBuf::from("sand.gif")))
+            })
+        );
+    }
+}
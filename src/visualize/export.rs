@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use super::{Frame, Palette};
+
+/// Writes every frame, caption first, with a blank line between frames.
+pub fn text(frames: impl Iterator<Item = Frame>, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    for frame in frames {
+        writeln!(file, "{}", frame)?;
+    }
+    file.flush()
+}
+
+/// The frame's cells, each drawn as a `scale` x `scale` square, cropped or padded with the
+/// background colour to `width` x `height` cells. One `T` per pixel, row by row.
+fn pixels<T: Copy>(
+    frame: &Frame,
+    (width, height): (usize, usize),
+    scale: usize,
+    colour: impl Fn(char) -> T,
+) -> Vec<T> {
+    let background = colour(' ');
+    let mut pixels = Vec::with_capacity(width * height * scale * scale);
+    for y in 0..height {
+        let row: Vec<T> = (0..width)
+            .flat_map(|x| {
+                let cell = frame.cells.get(x, y).map_or(background, |&c| colour(c));
+                std::iter::repeat_n(cell, scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&row);
+        }
+    }
+    pixels
+}
+
+/// Writes every frame as a binary PPM image `frame-00000.ppm`, `frame-00001.ppm`, ... in
+/// `directory`, all the size of the first frame.
+pub fn ppm(
+    frames: impl Iterator<Item = Frame>,
+    directory: &Path,
+    scale: usize,
+    palette: Palette,
+) -> io::Result<()> {
+    fs::create_dir_all(directory)?;
+    let mut size = None;
+    for (index, frame) in frames.enumerate() {
+        let (width, height) = *size.get_or_insert((frame.cells.width(), frame.cells.height()));
+        let mut file = BufWriter::new(File::create(
+            directory.join(format!("frame-{:05}.ppm", index)),
+        )?);
+        write!(file, "P6\n{} {}\n255\n", width * scale, height * scale)?;
+        file.write_all(&pixels(&frame, (width, height), scale, palette).concat())?;
+        file.flush()?;
+    }
+    Ok(())
+}
+
+/// Writes an animated GIF looping over every frame, all the size of the first frame.
+/// Colours come from `palette` applied to ASCII chars, so that frames can be encoded as they
+/// come: non-ASCII chars are drawn with the background colour.
+pub fn gif(
+    frames: impl Iterator<Item = Frame>,
+    path: &Path,
+    scale: usize,
+    fps: u32,
+    palette: Palette,
+) -> io::Result<()> {
+    let mut colours: Vec<[u8; 3]> = Vec::new();
+    let mut indexes = [0_u8; 128];
+    for c in (0..128_u8).map(char::from) {
+        let colour = palette(c);
+        let index = colours
+            .iter()
+            .position(|&known| known == colour)
+            .unwrap_or_else(|| {
+                colours.push(colour);
+                colours.len() - 1
+            });
+        indexes[c as usize] = index as u8;
+    }
+    let background = indexes[b' ' as usize];
+    let colour_index = |c: char| match c.is_ascii() {
+        true => indexes[c as usize],
+        false => background,
+    };
+    // the colour table size is a power of two, at least 2 colours for the format
+    let depth = (1..=7)
+        .find(|&bits| colours.len() <= 1 << bits)
+        .unwrap_or(7);
+
+    let mut frames = frames.peekable();
+    let (width, height) = match frames.peek() {
+        Some(frame) => (frame.cells.width(), frame.cells.height()),
+        None => (0, 0),
+    };
+    let screen = |cells: usize| -> io::Result<u16> {
+        u16::try_from(cells * scale).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frames are too large for a GIF",
+            )
+        })
+    };
+    let (screen_width, screen_height) = (screen(width)?, screen(height)?);
+
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(b"GIF89a")?;
+    file.write_all(&screen_width.to_le_bytes())?;
+    file.write_all(&screen_height.to_le_bytes())?;
+    // global colour table, 8 bits per channel, no background or aspect ratio
+    file.write_all(&[0x80 | 0x70 | (depth as u8 - 1), 0, 0])?;
+    for index in 0..1 << depth {
+        file.write_all(colours.get(index).unwrap_or(&[0, 0, 0]))?;
+    }
+    // loop forever
+    file.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+    let delay = (100 / fps.max(1)).max(2) as u16;
+    for frame in frames {
+        file.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        file.write_all(&delay.to_le_bytes())?;
+        file.write_all(&[0x00, 0x00])?;
+
+        file.write_all(&[0x2c, 0, 0, 0, 0])?;
+        file.write_all(&screen_width.to_le_bytes())?;
+        file.write_all(&screen_height.to_le_bytes())?;
+        file.write_all(&[0x00])?;
+
+        let min_code_size = depth.max(2) as u8;
+        let data = lzw(
+            &pixels(&frame, (width, height), scale, colour_index),
+            min_code_size,
+        );
+        file.write_all(&[min_code_size])?;
+        for block in data.chunks(255) {
+            file.write_all(&[block.len() as u8])?;
+            file.write_all(block)?;
+        }
+        file.write_all(&[0x00])?;
+    }
+    file.write_all(&[0x3b])?;
+    file.flush()
+}
+
+/// Variable-width LZW as used by GIF: codes start at `min_code_size + 1` bits, grow up to 12
+/// bits, and the dictionary is cleared when it is full.
+fn lzw(indexes: &[u8], min_code_size: u8) -> Vec<u8> {
+    const MAX_CODE: u16 = 4095;
+    let clear = 1_u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut output = BitWriter::default();
+    let mut width = min_code_size as u32 + 1;
+
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    // the highest code in use, and the first one that needs another bit
+    let mut high = end;
+    let mut overflow = 1_u16 << width;
+    output.write(clear, width);
+
+    let mut pixels = indexes.iter();
+    if let Some(&first) = pixels.next() {
+        let mut code = first as u16;
+        for &index in pixels {
+            if let Some(&known) = table.get(&(code, index)) {
+                code = known;
+                continue;
+            }
+            output.write(code, width);
+            high += 1;
+            if high == overflow {
+                width += 1;
+                overflow <<= 1;
+            }
+            if high == MAX_CODE {
+                output.write(clear, width);
+                table.clear();
+                width = min_code_size as u32 + 1;
+                high = end;
+                overflow = 1 << width;
+            } else {
+                table.insert((code, index), high);
+            }
+            code = index as u16;
+        }
+        output.write(code, width);
+        // the decoder still adds an entry for this last code
+        high += 1;
+        if high == overflow {
+            width += 1;
+        }
+        if high == MAX_CODE {
+            output.write(clear, width);
+            width = min_code_size as u32 + 1;
+        }
+    }
+    output.write(end, width);
+    output.finish()
+}
+
+/// Packs codes least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.bits |= (code as u32) << self.count;
+        self.count += width;
+        while self.count >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plain GIF LZW decoder, to check the encoder against.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1_usize << min_code_size;
+        let mut reader = data
+            .iter()
+            .flat_map(|byte| (0..8).map(move |bit| (byte >> bit) & 1));
+        let mut read = |width: u32| -> usize {
+            (0..width).fold(0, |code, bit| {
+                code | ((reader.next().unwrap() as usize) << bit)
+            })
+        };
+        let mut output = Vec::new();
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut width = min_code_size as u32 + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        loop {
+            let code = read(width);
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                width = min_code_size as u32 + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return output;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("invalid code {}", code),
+            };
+            output.extend(&entry);
+            if let Some(previous) = previous {
+                table.push([previous, vec![entry[0]]].concat());
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut state = 0x2022_u32;
+        let mut noise = || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            (state >> 16) as u8
+        };
+        let inputs: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![1],
+            vec![0; 10_000],
+            (0..20_000).map(|_| noise() % 4).collect(),
+            (0..50_000).map(|i| ((i / 7) % 3) as u8).collect(),
+        ];
+        for input in inputs {
+            assert_eq!(unlzw(&lzw(&input, 2), 2), input);
+        }
+        let wide: Vec<u8> = (0..30_000).map(|_| noise() % 100).collect();
+        assert_eq!(unlzw(&lzw(&wide, 7), 7), wide);
+    }
+}
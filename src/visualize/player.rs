@@ -0,0 +1,173 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use super::Frame;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How many past frames are kept to step back through.
+const HISTORY: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Key {
+    Pause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    fn from_byte(byte: u8) -> Option<Key> {
+        match byte {
+            b' ' | b'p' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Forward),
+            b'b' | b',' => Some(Key::Back),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' => Some(Key::Slower),
+            b'q' => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Switches the terminal to reading keys without waiting for enter, through `stty` so that no
+/// terminal library is needed, and restores it when dropped. Where `stty` isn't available
+/// the controls still work, followed by enter.
+struct RawMode {
+    saved: Option<String>,
+}
+
+impl RawMode {
+    fn stty(args: &[&str]) -> Option<String> {
+        let output = Command::new("stty")
+            .args(args)
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn enable() -> RawMode {
+        let saved = RawMode::stty(&["-g"]);
+        if saved.is_some() {
+            RawMode::stty(&["-icanon", "-echo", "min", "1"]);
+        }
+        RawMode { saved }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            RawMode::stty(&[saved]);
+        }
+    }
+}
+
+fn draw(frame: &Frame, index: usize, fps: u32, paused: bool) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    // clear the screen and go back to the top left corner
+    write!(stdout, "\x1b[2J\x1b[H{}", frame.cells)?;
+    writeln!(
+        stdout,
+        "{}{}{} {}frame {}, {} fps{}{}",
+        ANSI_BOLD,
+        frame.caption,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        index,
+        fps,
+        if paused { ", paused" } else { "" },
+        ANSI_RESET
+    )?;
+    writeln!(
+        stdout,
+        "space: play/pause, n/b: step forward/back, +/-: speed, q: quit"
+    )?;
+    stdout.flush()
+}
+
+/// Plays `frames` in the terminal at `fps` frames per second, until they run out and the
+/// user quits.
+pub fn play(frames: impl Iterator<Item = Frame>, fps: u32) -> io::Result<()> {
+    let _raw_mode = RawMode::enable();
+    let (keys, pressed) = mpsc::channel();
+    thread::spawn(move || {
+        for byte in io::stdin().lock().bytes() {
+            let Ok(byte) = byte else { break };
+            if let Some(key) = Key::from_byte(byte) {
+                if keys.send(key).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let mut frames = frames.enumerate();
+    let mut history: VecDeque<(usize, Frame)> = VecDeque::new();
+    // position in `history` of the frame on screen
+    let mut cursor = 0;
+    let mut fps = fps.max(1);
+    let mut paused = false;
+    let mut advance = |history: &mut VecDeque<(usize, Frame)>, cursor: &mut usize| -> bool {
+        if *cursor + 1 < history.len() {
+            *cursor += 1;
+            return true;
+        }
+        match frames.next() {
+            Some(frame) => {
+                history.push_back(frame);
+                if history.len() > HISTORY {
+                    history.pop_front();
+                }
+                *cursor = history.len() - 1;
+                true
+            }
+            None => false,
+        }
+    };
+    if !advance(&mut history, &mut cursor) {
+        return Ok(());
+    }
+
+    loop {
+        let (index, frame) = &history[cursor];
+        draw(frame, *index, fps, paused)?;
+        match pressed.recv_timeout(Duration::from_millis(1000 / fps as u64)) {
+            Ok(Key::Quit) => return Ok(()),
+            Ok(Key::Pause) => paused = !paused,
+            Ok(Key::Forward) => {
+                paused = true;
+                advance(&mut history, &mut cursor);
+            }
+            Ok(Key::Back) => {
+                paused = true;
+                cursor = cursor.saturating_sub(1);
+            }
+            Ok(Key::Faster) => fps = (fps * 2).min(1000),
+            Ok(Key::Slower) => fps = (fps / 2).max(1),
+            Err(RecvTimeoutError::Timeout) if !paused => {
+                // stay on the last frame once the simulation is over
+                paused = !advance(&mut history, &mut cursor);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            // no more input: play until the end
+            Err(RecvTimeoutError::Disconnected) => {
+                while advance(&mut history, &mut cursor) {
+                    let (index, frame) = &history[cursor];
+                    draw(frame, *index, fps, false)?;
+                    thread::sleep(Duration::from_millis(1000 / fps as u64));
+                }
+                return Ok(());
+            }
+        }
+    }
+}